@@ -181,3 +181,99 @@ fn add_assign(b: &mut Bencher) {
 		b.iter(|| bsl64a.add_assign(bsl64b.iter().copied()));
 	}
 }
+
+/* `count_ones` masks the partial edge elements of a slice rather than testing
+them bit by bit, so these sets compare aligned, misaligned, and single-element
+slices. The misaligned cases hit both the head and tail edges.
+*/
+#[bench]
+fn count_ones(b: &mut Bencher) {
+	let src = [0xA5u8; 64];
+	let bsb08 = src.bits::<Msb0>();
+	let bsl08 = src.bits::<Lsb0>();
+	b.iter(|| black_box(bsb08).count_ones());
+	b.iter(|| black_box(bsl08).count_ones());
+	b.iter(|| black_box(&bsb08[3 .. 509]).count_ones());
+	b.iter(|| black_box(&bsl08[3 .. 509]).count_ones());
+	b.iter(|| black_box(&bsb08[1 .. 6]).count_ones());
+	b.iter(|| black_box(&bsl08[1 .. 6]).count_ones());
+
+	let src = [0xA5A5u16; 32];
+	let bsb16 = src.bits::<Msb0>();
+	let bsl16 = src.bits::<Lsb0>();
+	b.iter(|| black_box(bsb16).count_ones());
+	b.iter(|| black_box(bsl16).count_ones());
+	b.iter(|| black_box(&bsb16[3 .. 509]).count_ones());
+	b.iter(|| black_box(&bsl16[3 .. 509]).count_ones());
+	b.iter(|| black_box(&bsb16[1 .. 14]).count_ones());
+	b.iter(|| black_box(&bsl16[1 .. 14]).count_ones());
+
+	let src = [0xA5A5_A5A5u32; 16];
+	let bsb32 = src.bits::<Msb0>();
+	let bsl32 = src.bits::<Lsb0>();
+	b.iter(|| black_box(bsb32).count_ones());
+	b.iter(|| black_box(bsl32).count_ones());
+	b.iter(|| black_box(&bsb32[3 .. 509]).count_ones());
+	b.iter(|| black_box(&bsl32[3 .. 509]).count_ones());
+	b.iter(|| black_box(&bsb32[1 .. 30]).count_ones());
+	b.iter(|| black_box(&bsl32[1 .. 30]).count_ones());
+
+	#[cfg(target_pointer_width = "64")]
+	{
+		let src = [0xA5A5_A5A5_A5A5_A5A5u64; 8];
+		let bsb64 = src.bits::<Msb0>();
+		let bsl64 = src.bits::<Lsb0>();
+		b.iter(|| black_box(bsb64).count_ones());
+		b.iter(|| black_box(bsl64).count_ones());
+		b.iter(|| black_box(&bsb64[3 .. 509]).count_ones());
+		b.iter(|| black_box(&bsl64[3 .. 509]).count_ones());
+		b.iter(|| black_box(&bsb64[1 .. 62]).count_ones());
+		b.iter(|| black_box(&bsl64[1 .. 62]).count_ones());
+	}
+}
+
+/* `copy_from_slice` moves whole elements, shifting them when the source and
+destination begin at different offsets. `clone_from_slice` accepts differing
+types, and so copies bit by bit; it is included for comparison.
+*/
+#[bench]
+fn copy_from_slice(b: &mut Bencher) {
+	let src = [0xA5u8; 64];
+	let mut dst = [0u8; 64];
+	let srcb08 = src.bits::<Msb0>();
+	let dstb08 = dst.bits_mut::<Msb0>();
+	b.iter(|| dstb08.copy_from_slice(black_box(srcb08)));
+	b.iter(|| dstb08[5 .. 500].copy_from_slice(black_box(&srcb08[3 .. 498])));
+	b.iter(|| dstb08[5 .. 500].clone_from_slice(black_box(&srcb08[3 .. 498])));
+
+	let src = [0xA5A5u16; 32];
+	let mut dst = [0u16; 32];
+	let srcl16 = src.bits::<Lsb0>();
+	let dstl16 = dst.bits_mut::<Lsb0>();
+	b.iter(|| dstl16.copy_from_slice(black_box(srcl16)));
+	b.iter(|| dstl16[5 .. 500].copy_from_slice(black_box(&srcl16[3 .. 498])));
+	b.iter(|| dstl16[5 .. 500].clone_from_slice(black_box(&srcl16[3 .. 498])));
+
+	let src = [0xA5A5_A5A5u32; 16];
+	let mut dst = [0u32; 16];
+	let srcb32 = src.bits::<Msb0>();
+	let dstb32 = dst.bits_mut::<Msb0>();
+	b.iter(|| dstb32.copy_from_slice(black_box(srcb32)));
+	b.iter(|| dstb32[5 .. 500].copy_from_slice(black_box(&srcb32[3 .. 498])));
+	b.iter(|| dstb32[5 .. 500].clone_from_slice(black_box(&srcb32[3 .. 498])));
+
+	#[cfg(target_pointer_width = "64")]
+	{
+		let src = [0xA5A5_A5A5_A5A5_A5A5u64; 8];
+		let mut dst = [0u64; 8];
+		let srcl64 = src.bits::<Lsb0>();
+		let dstl64 = dst.bits_mut::<Lsb0>();
+		b.iter(|| dstl64.copy_from_slice(black_box(srcl64)));
+		b.iter(|| {
+			dstl64[5 .. 500].copy_from_slice(black_box(&srcl64[3 .. 498]))
+		});
+		b.iter(|| {
+			dstl64[5 .. 500].clone_from_slice(black_box(&srcl64[3 .. 498]))
+		});
+	}
+}
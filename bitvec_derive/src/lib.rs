@@ -0,0 +1,567 @@
+/*! Typed bit-field accessors for `bitvec` storage.
+
+This crate provides `#[derive(BitFields)]`, which reads `#[bitfield]` attributes
+placed on a storage field of a struct and generates a getter and a setter for
+each named bit range in that storage. The accessors are built on the
+[`BitField`] family of traits, so the storage may be anything that dereferences
+to a `BitSlice`: a `BitArray`, a `&mut BitSlice`, or a `BitBox` or `BitVec`.
+
+# Attribute Syntax
+
+Each `#[bitfield]` attribute declares one field:
+
+```text
+#[bitfield(VISIBILITY NAME: TYPE = RANGE, ENDIAN)]
+```
+
+- `VISIBILITY`: Optional. The visibility of the generated methods.
+- `NAME`: The getter is named `NAME`, and the setter `set_NAME`.
+- `TYPE`: `bool`, an unsigned integer from `u8` to `u128`, or a signed integer
+  from `i8` to `i64`. Signed fields are sign-extended from their most
+  significant bit. `usize` and `isize` fields are as wide as the target allows.
+- `RANGE`: The bits of the storage that hold the field, written as `a .. b`,
+  `a ..= b`, or `a` for a single bit. The bounds must be integer literals.
+- `ENDIAN`: Optional. `le` or `be` selects the `_le` or `_be` methods of
+  `BitField`. Without it, the native `load` and `store` methods are used.
+  `bool` fields are a single bit, and do not accept it.
+
+# Compile-Time Checks
+
+The derive rejects fields that are empty, that are wider than their type, or
+that overlap another field in the same storage. The width of `usize` and
+`isize` depends on the target, so fields of those types are checked when the
+generated code is compiled. When the storage is a `BitArray` in a non-generic
+struct, it also rejects fields that end beyond the array:
+
+```rust,compile_fail
+use bitvec::prelude::*;
+use bitvec_derive::BitFields;
+
+#[derive(BitFields)]
+struct Status {
+    #[bitfield(code: u8 = 12 .. 20)]
+    raw: BitArray<Msb0, [u8; 2]>,
+}
+```
+
+Storage behind a shared reference (`&BitSlice`) receives only getters.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec_derive::BitFields;
+
+#[derive(BitFields)]
+struct Ipv4Header {
+    #[bitfield(pub version: u8 = 0 .. 4, be)]
+    #[bitfield(pub ihl: u8 = 4 .. 8, be)]
+    #[bitfield(pub total_length: u16 = 16 .. 32, be)]
+    #[bitfield(pub dont_fragment: bool = 49)]
+    #[bitfield(pub fragment_offset: u16 = 51 .. 64, be)]
+    #[bitfield(pub ttl: u8 = 64 .. 72, be)]
+    raw: BitArray<Msb0, [u8; 20]>,
+}
+
+let mut header = Ipv4Header { raw: BitArray::new([0; 20]) };
+header.set_version(4);
+header.set_ihl(5);
+header.set_total_length(1500);
+header.set_dont_fragment(true);
+assert_eq!(header.raw.as_slice()[.. 4], [0x45, 0x00, 0x05, 0xDC]);
+assert_eq!(header.raw.as_slice()[6], 0x40);
+assert_eq!(header.total_length(), 1500);
+assert!(header.dont_fragment());
+```
+
+[`BitField`]: https://docs.rs/bitvec/latest/bitvec/fields/trait.BitField.html
+!*/
+
+extern crate proc_macro;
+
+use proc_macro2::{
+	Span,
+	TokenStream,
+};
+
+use quote::{
+	format_ident,
+	quote,
+	quote_spanned,
+};
+
+use syn::{
+	parse::{
+		Parse,
+		ParseStream,
+	},
+	parse_macro_input,
+	spanned::Spanned,
+	Data,
+	DeriveInput,
+	Error,
+	Fields,
+	Ident,
+	LitInt,
+	Member,
+	Token,
+	Type,
+	Visibility,
+};
+
+/// Generates bit-field accessors from `#[bitfield]` attributes.
+///
+/// See the crate documentation for the attribute syntax.
+#[proc_macro_derive(BitFields, attributes(bitfield))]
+pub fn derive_bit_fields(
+	input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input)
+		.unwrap_or_else(|err| err.to_compile_error())
+		.into()
+}
+
+/// The `BitField` method family that accesses a field of some type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+	/// `bool`, which is a single bit.
+	Bool,
+	/// Unsigned integers, through `BitField`.
+	Unsigned,
+	/// Signed integers, through `BitFieldSigned`.
+	Signed,
+	/// `u128`, through `BitFieldWide`.
+	Wide,
+}
+
+/// One `#[bitfield]` attribute.
+struct Spec {
+	/// The visibility of the generated methods.
+	vis: Visibility,
+	/// The name of the getter.
+	name: Ident,
+	/// The type of the field.
+	ty: Type,
+	/// The first bit of the field.
+	start: usize,
+	/// The bit after the last bit of the field.
+	end: usize,
+	/// `le`, `be`, or nothing for the native methods.
+	endian: Option<Ident>,
+	/// The location of the attribute, for diagnostics.
+	span: Span,
+}
+
+impl Parse for Spec {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let span = input.span();
+		let vis = input.parse()?;
+		let name = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty = input.parse()?;
+		input.parse::<Token![=]>()?;
+		let start = input.parse::<LitInt>()?.base10_parse::<usize>()?;
+		let end = if input.peek(Token![..=]) {
+			input.parse::<Token![..=]>()?;
+			input.parse::<LitInt>()?.base10_parse::<usize>()? + 1
+		}
+		else if input.peek(Token![..]) {
+			input.parse::<Token![..]>()?;
+			input.parse::<LitInt>()?.base10_parse::<usize>()?
+		}
+		else {
+			start + 1
+		};
+		let endian = if input.is_empty() {
+			None
+		}
+		else {
+			input.parse::<Token![,]>()?;
+			let endian = input.parse::<Ident>()?;
+			if endian != "le" && endian != "be" {
+				return Err(Error::new(
+					endian.span(),
+					"expected `le` or `be`",
+				));
+			}
+			if let Ok((Kind::Bool, _)) = classify(&ty) {
+				return Err(Error::new(
+					endian.span(),
+					"`bool` fields are a single bit, and have no byte order",
+				));
+			}
+			Some(endian)
+		};
+		Ok(Self {
+			vis,
+			name,
+			ty,
+			start,
+			end,
+			endian,
+			span,
+		})
+	}
+}
+
+/** Classifies a field type, and finds how many bits it can hold.
+
+# Returns
+
+The method family for the type, and its width in bits. The width of `usize` and
+`isize` depends on the target, so it is `None` for them, and [`widths`] checks
+their fields in the generated code instead.
+
+[`widths`]: fn.widths.html
+**/
+fn classify(ty: &Type) -> syn::Result<(Kind, Option<usize>)> {
+	let ident = match ty {
+		Type::Path(path) if path.qself.is_none() => path.path.get_ident(),
+		_ => None,
+	};
+	let name = ident.map(Ident::to_string).unwrap_or_default();
+	Ok(match name.as_str() {
+		"bool" => (Kind::Bool, Some(1)),
+		"u8" => (Kind::Unsigned, Some(8)),
+		"u16" => (Kind::Unsigned, Some(16)),
+		"u32" => (Kind::Unsigned, Some(32)),
+		"u64" => (Kind::Unsigned, Some(64)),
+		"usize" => (Kind::Unsigned, None),
+		"u128" => (Kind::Wide, Some(128)),
+		"i8" => (Kind::Signed, Some(8)),
+		"i16" => (Kind::Signed, Some(16)),
+		"i32" => (Kind::Signed, Some(32)),
+		"i64" => (Kind::Signed, Some(64)),
+		"isize" => (Kind::Signed, None),
+		_ => {
+			return Err(Error::new(
+				ty.span(),
+				"bit-fields must be `bool`, `u8` through `u128`, or `i8` \
+				 through `i64`",
+			));
+		},
+	})
+}
+
+/// Checks that a set of fields fit their types and do not overlap.
+fn check(specs: &[Spec]) -> syn::Result<()> {
+	let mut errors = Vec::new();
+	for spec in specs {
+		let (_, bits) = match classify(&spec.ty) {
+			Ok(class) => class,
+			Err(err) => {
+				errors.push(err);
+				continue;
+			},
+		};
+		let width = spec.end.saturating_sub(spec.start);
+		if width == 0 {
+			errors.push(Error::new(
+				spec.span,
+				format!("`{}` has no bits", spec.name),
+			));
+		}
+		else if let Some(bits) = bits.filter(|&bits| width > bits) {
+			errors.push(Error::new(
+				spec.span,
+				format!(
+					"`{}` is {} bits wide, which does not fit in a {}-bit type",
+					spec.name, width, bits,
+				),
+			));
+		}
+	}
+
+	//  Compare each field against the one, of those starting before it, that
+	//  reaches furthest.
+	let mut order = specs.iter().collect::<Vec<_>>();
+	order.sort_by_key(|spec| spec.start);
+	let mut reach: Option<&Spec> = None;
+	for next in order {
+		if let Some(prev) = reach {
+			if next.start < prev.end {
+				errors.push(Error::new(
+					next.span,
+					format!(
+						"`{}` (bits {}..{}) overlaps `{}` (bits {}..{})",
+						next.name,
+						next.start,
+						next.end,
+						prev.name,
+						prev.start,
+						prev.end,
+					),
+				));
+			}
+			if next.end <= prev.end {
+				continue;
+			}
+		}
+		reach = Some(next);
+	}
+
+	let mut errors = errors.into_iter();
+	match errors.next() {
+		None => Ok(()),
+		Some(mut first) => {
+			for err in errors {
+				first.combine(err);
+			}
+			Err(first)
+		},
+	}
+}
+
+/** Generates the getter and setter for one field.
+
+# Parameters
+
+- `spec`: The field.
+- `member`: The storage field of the struct.
+- `mutable`: Whether the storage can be written, and so receives a setter.
+**/
+fn accessors(spec: &Spec, member: &Member, mutable: bool) -> TokenStream {
+	let Spec {
+		vis,
+		name,
+		ty,
+		start,
+		end,
+		..
+	} = spec;
+	let setter = format_ident!("set_{}", name);
+	let (kind, _) = classify(ty).expect("fields are checked before expansion");
+	let suffix = match &spec.endian {
+		Some(endian) => format!("_{}", endian),
+		None => String::new(),
+	};
+	let (trait_path, family) = match kind {
+		Kind::Bool => (quote!(), ""),
+		Kind::Unsigned => (quote!(::bitvec::fields::BitField), ""),
+		Kind::Signed => (quote!(::bitvec::fields::BitFieldSigned), "_signed"),
+		Kind::Wide => (quote!(::bitvec::fields::BitFieldWide), "_u128"),
+	};
+	let load = format_ident!("load{}{}", suffix, family);
+	let store = format_ident!("store{}{}", suffix, family);
+
+	let bits = quote!(::core::ops::Deref::deref(&self.#member));
+	let bits_mut = quote!(::core::ops::DerefMut::deref_mut(&mut self.#member));
+	let (get, set) = match kind {
+		Kind::Bool => (
+			quote!(#bits[#start]),
+			quote!(#bits_mut.set(#start, value)),
+		),
+		_ => (
+			quote!(#trait_path::#load(&#bits[#start .. #end])),
+			quote!(#trait_path::#store(&mut #bits_mut[#start .. #end], value)),
+		),
+	};
+
+	let getter = quote_spanned! { spec.span =>
+		#[inline]
+		#vis fn #name(&self) -> #ty {
+			#get
+		}
+	};
+	if !mutable {
+		return getter;
+	}
+	quote_spanned! { spec.span =>
+		#getter
+
+		#[inline]
+		#vis fn #setter(&mut self, value: #ty) {
+			#set
+		}
+	}
+}
+
+/** Generates a compile-time check that fields end inside a `BitArray`.
+
+This can only be done when the storage type is nameable outside the struct, so
+it is skipped for generic structs and for storage other than `BitArray`.
+**/
+fn bounds(input: &DeriveInput, ty: &Type, specs: &[Spec]) -> TokenStream {
+	let is_array = match ty {
+		Type::Path(path) => path
+			.path
+			.segments
+			.last()
+			.map(|seg| seg.ident == "BitArray")
+			.unwrap_or(false),
+		_ => false,
+	};
+	let end = specs.iter().map(|spec| spec.end).max().unwrap_or(0);
+	if !is_array || !input.generics.params.is_empty() {
+		return quote!();
+	}
+	quote_spanned! { ty.span() =>
+		const _: [(); 0] =
+			[(); (#end > 8 * ::core::mem::size_of::<#ty>()) as usize];
+	}
+}
+
+/** Generates compile-time checks that `usize` and `isize` fields fit their
+types.
+
+The width of these types depends on the target, so the derive cannot check the
+fields itself, and instead asks the compiler to.
+**/
+fn widths(specs: &[Spec]) -> TokenStream {
+	specs
+		.iter()
+		.filter(|spec| match classify(&spec.ty) {
+			Ok((_, bits)) => bits.is_none(),
+			Err(_) => false,
+		})
+		.map(|spec| {
+			let ty = &spec.ty;
+			let width = spec.end - spec.start;
+			quote_spanned! { spec.span =>
+				const _: [(); 0] =
+					[(); (#width > 8 * ::core::mem::size_of::<#ty>()) as usize];
+			}
+		})
+		.collect()
+}
+
+/// Expands `#[derive(BitFields)]`.
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => {
+			return Err(Error::new(
+				input.ident.span(),
+				"`BitFields` can only be derived for structs",
+			));
+		},
+	};
+	let members = match fields {
+		Fields::Named(_) | Fields::Unnamed(_) => fields.iter().enumerate(),
+		Fields::Unit => {
+			return Err(Error::new(
+				input.ident.span(),
+				"`BitFields` requires a storage field",
+			));
+		},
+	};
+
+	let mut methods = TokenStream::new();
+	let mut checks = TokenStream::new();
+	for (idx, field) in members {
+		let specs = field
+			.attrs
+			.iter()
+			.filter(|attr| attr.path.is_ident("bitfield"))
+			.map(|attr| attr.parse_args::<Spec>())
+			.collect::<syn::Result<Vec<_>>>()?;
+		if specs.is_empty() {
+			continue;
+		}
+		check(&specs)?;
+
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(idx.into()),
+		};
+		let mutable = match &field.ty {
+			Type::Reference(r) => r.mutability.is_some(),
+			_ => true,
+		};
+		for spec in &specs {
+			methods.extend(accessors(spec, &member, mutable));
+		}
+		checks.extend(widths(&specs));
+		checks.extend(bounds(input, &field.ty, &specs));
+	}
+	if methods.is_empty() {
+		return Err(Error::new(
+			input.ident.span(),
+			"`BitFields` requires a `#[bitfield]` attribute on a storage field",
+		));
+	}
+
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) =
+		input.generics.split_for_impl();
+	Ok(quote! {
+		#checks
+
+		impl #impl_generics #name #ty_generics #where_clause {
+			#methods
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn errors(src: &str) -> String {
+		let input = syn::parse_str::<DeriveInput>(src).unwrap();
+		match expand(&input) {
+			Ok(_) => String::new(),
+			Err(err) => err.to_compile_error().to_string(),
+		}
+	}
+
+	#[test]
+	fn accepted() {
+		assert!(errors(
+			"struct S {
+				#[bitfield(pub a: u8 = 0 .. 8, be)]
+				#[bitfield(b: i16 = 8 ..= 23, le)]
+				#[bitfield(c: bool = 24)]
+				#[bitfield(d: u128 = 25 .. 153)]
+				raw: BitArray<Msb0, [u8; 20]>,
+			}"
+		)
+		.is_empty());
+	}
+
+	#[test]
+	fn rejected() {
+		let err = errors(
+			"struct S {
+				#[bitfield(a: u8 = 0 .. 9)]
+				#[bitfield(b: u8 = 12 .. 12)]
+				#[bitfield(c: u16 = 4 .. 16)]
+				#[bitfield(d: f32 = 20 .. 24)]
+				raw: BitArray<Msb0, [u8; 4]>,
+			}",
+		);
+		assert!(err.contains("9 bits wide, which does not fit in a 8-bit"));
+		assert!(err.contains("`b` has no bits"));
+		assert!(err.contains("`c` (bits 4..16) overlaps `a` (bits 0..9)"));
+		assert!(err.contains("bit-fields must be"));
+
+		let err = errors("struct S(#[bitfield(a: u8 = 0 .. 4, ne)] [u8; 1]);");
+		assert!(err.contains("expected `le` or `be`"));
+		let err = errors("struct S(#[bitfield(a: bool = 3, be)] [u8; 1]);");
+		assert!(err.contains("`bool` fields are a single bit"));
+		let err = errors("struct S { raw: [u8; 4] }");
+		assert!(err.contains("requires a `#[bitfield]` attribute"));
+	}
+
+	/// `usize` and `isize` fields are left for the compiler to check against
+	/// the target.
+	#[test]
+	fn target_width() {
+		let input = syn::parse_str::<DeriveInput>(
+			"struct S {
+				#[bitfield(a: usize = 0 .. 72)]
+				#[bitfield(b: isize = 72 ..= 80)]
+				raw: BitArray<Msb0, [u8; 16]>,
+			}",
+		)
+		.unwrap();
+		let out = expand(&input).unwrap().to_string();
+		let check = |width, ty| {
+			let size = format!(":: core :: mem :: size_of :: < {} >", ty);
+			format!("{}usize > 8 * {}", width, size)
+		};
+		assert!(out.contains(&check(72, "usize")));
+		assert!(out.contains(&check(9, "isize")));
+	}
+}
@@ -0,0 +1,89 @@
+//! Exercises the generated accessors over each kind of storage.
+
+use bitvec::prelude::*;
+use bitvec_derive::BitFields;
+
+#[derive(BitFields)]
+struct Register {
+	#[bitfield(enable: bool = 0)]
+	#[bitfield(mode: u8 = 1 ..= 3, le)]
+	#[bitfield(offset: i16 = 4 .. 16, be)]
+	#[bitfield(tag: u128 = 16 .. 116, le)]
+	raw: BitArray<Lsb0, [u32; 4]>,
+}
+
+#[derive(BitFields)]
+struct View<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	#[bitfield(pub kind: u8 = 0 .. 4, be)]
+	#[bitfield(pub value: u32 = 4 .. 28, be)]
+	bits: &'a mut BitSlice<O, T>,
+}
+
+#[derive(BitFields)]
+struct Flags<'a>(
+	#[bitfield(first: bool = 0)]
+	#[bitfield(rest: u8 = 1 .. 8)]
+	&'a BitSlice<Msb0, u8>,
+);
+
+#[derive(BitFields)]
+struct Packet {
+	#[bitfield(length: u16 = 0 .. 12, le)]
+	#[bitfield(level: i8 = 12 .. 16)]
+	data: BitVec<Msb0, u8>,
+}
+
+#[test]
+fn array() {
+	let mut reg = Register {
+		raw: BitArray::new([0; 4]),
+	};
+	reg.set_enable(true);
+	reg.set_mode(5);
+	reg.set_offset(-300);
+	reg.set_tag(!0 >> 28);
+	assert!(reg.enable());
+	assert_eq!(reg.mode(), 5);
+	assert_eq!(reg.offset(), -300);
+	assert_eq!(reg.tag(), !0 >> 28);
+	assert_eq!(reg.raw[.. 4].load_le::<u8>(), 0b1011);
+	assert_eq!(reg.raw[116 ..].load_le::<u16>(), 0);
+
+	reg.set_mode(0);
+	assert!(reg.enable());
+	assert_eq!(reg.offset(), -300);
+}
+
+#[test]
+fn slice() {
+	let mut data = [0u16; 2];
+	let mut view = View {
+		bits: &mut data.bits_mut::<Msb0>()[2 ..],
+	};
+	view.set_kind(0xA);
+	view.set_value(0x12_3456);
+	assert_eq!(view.kind(), 0xA);
+	assert_eq!(view.value(), 0x12_3456);
+	assert_eq!(data, [0x2848, 0xD158]);
+
+	let data = [0x95u8];
+	let flags = Flags(data.bits::<Msb0>());
+	assert!(flags.first());
+	assert_eq!(flags.rest(), 0x15);
+}
+
+#[test]
+fn vec() {
+	let mut packet = Packet {
+		data: bitvec![Msb0, u8; 0; 16],
+	};
+	packet.set_length(0xABC);
+	packet.set_level(-2);
+	assert_eq!(packet.length(), 0xABC);
+	assert_eq!(packet.level(), -2);
+	assert_eq!(packet.data.as_slice(), &[0xBC, 0xAE]);
+}
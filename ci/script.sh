@@ -14,6 +14,8 @@ fi
 
 $CARGO clean
 $CARGO build $CARGO_TARGET --all-features
+$CARGO build $CARGO_TARGET --manifest-path bitvec_derive/Cargo.toml
 if [ -z $DISABLE_TESTS ]; then
     $CARGO test $CARGO_TARGET --all-features
+    $CARGO test $CARGO_TARGET --manifest-path bitvec_derive/Cargo.toml
 fi
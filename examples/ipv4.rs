@@ -1,10 +1,10 @@
 /*! This example demonstrates building an IPv4 packet header using `BitField`.
 
-There are still some flaws and missing functionality in the `BitField` trait,
-which provides capabilities for addressing regions of memory other than the
-fundamental types as storage/value slots, that hamper usability. However, for
-bitfields that *are* supported, the trait provides conveniences over bare
-shift/masks.
+IPv4 is a network-order format: its multi-byte fields are big-endian, and its
+bits are numbered from the most significant bit of each byte. `BitSlice<Msb0,
+u8>` matches this numbering, and the `_be` methods of `BitField` assign the
+bytes of a field in big-endian significance, so every field of the header, of
+any width and alignment, is a single `store_be` or `load_be` call.
 !*/
 
 extern crate bitvec;
@@ -16,34 +16,31 @@ fn build() -> [u8; 20] {
 	let pkt = raw_bytes.bits_mut::<Msb0>();
 
 	//  Set IPv4
-	pkt[.. 4].store(4u8);
+	pkt[.. 4].store_be(4u8);
 	//  Set an IHL of 5 words
-	pkt[4 .. 8].store(5u8);
+	pkt[4 .. 8].store_be(5u8);
 	//  Blank the DSCP and ECN fields
-	pkt[8 .. 14].store(0u8);
-	pkt[14 .. 16].store(0u8);
+	pkt[8 .. 14].store_be(0u8);
+	pkt[14 .. 16].store_be(0u8);
 
-	//  Set a total size of 20 bytes. This must be encoded as big-endian before
-	//  storage, as the storage API does not currently provide this.
-	pkt[16 .. 32].store(20u16.to_be());
+	//  Set a total size of 20 bytes.
+	pkt[16 .. 32].store_be(20u16);
 
 	//  Set the identification fingerprint
-	pkt[32 .. 48].store(0xC001u16.to_be());
+	pkt[32 .. 48].store_be(0xC001u16);
 
 	//  Set the flags
 	*pkt.get_mut(48).unwrap() = false;
 	*pkt.get_mut(49).unwrap() = true;
 	*pkt.get_mut(50).unwrap() = false;
 
-	/* And the fragment offset. Until `BitField` stabilizes byte-endian APIs,
-	this will remain unpleasant. `0u16` can be stored in `[51 .. 64]` without
-	trouble, as can `!0u16`, but no other bit pattern can be currently stored
-	correctly in IPv4’s expectation of big-endian byte ordering using the
-	`BitField` API on a little-endian machine. This will be addressed in a
-	future release.
-	*/
-	pkt[51 .. 56].store(0u8);
-	pkt[56 .. 64].store(0u8);
+	//  And the fragment offset, which is thirteen bits wide and so starts in
+	//  the middle of a byte.
+	pkt[51 .. 64].store_be(0u16);
+
+	//  Set the time to live, and the protocol number for TCP
+	pkt[64 .. 72].store_be(64u8);
+	pkt[72 .. 80].store_be(6u8);
 
 	//  There are no more bitfields in the IPv4 header, so the rest can be
 	//  filled normally
@@ -52,8 +49,7 @@ fn build() -> [u8; 20] {
 
 	//  Last, set the checksum
 	let csum = ipv4_csum(&raw_bytes[..]);
-	raw_bytes[10] = (csum >> 8) as u8;
-	raw_bytes[11] = (csum & 0x00FF) as u8;
+	raw_bytes.bits_mut::<Msb0>()[80 .. 96].store_be(csum);
 
 	raw_bytes
 }
@@ -64,13 +60,15 @@ fn parse(header: [u8; 20]) {
 
 	//  Check that the version field is `4`, by `load`ing it and by direct
 	//  inspection
-	assert_eq!(pkt[.. 4].load::<u8>(), 4);
+	assert_eq!(pkt[.. 4].load_be::<u8>(), 4);
 	assert_eq!(header[0] & 0xF0, 0x40);
 
-	let ihl = pkt[4 .. 8].load::<u8>() as usize;
+	let ihl = pkt[4 .. 8].load_be::<u8>() as usize;
 	assert!((5 .. 16).contains(&ihl));
+	assert_eq!(pkt[16 .. 32].load_be::<u16>() as usize, ihl * 4);
 	assert!(pkt[49], "Unexpected fragmentation");
 	assert!(!pkt[50], "Unexpected fragmentation");
+	assert_eq!(pkt[51 .. 64].load_be::<u16>(), 0, "Unexpected fragmentation");
 }
 
 fn main() {
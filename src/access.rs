@@ -20,24 +20,25 @@ use crate::{
 };
 
 use core::{
+	cell::Cell,
 	fmt::Debug,
 	sync::atomic::Ordering,
 };
 
-use radium::{
-	marker::BitOps,
-	Radium,
-};
+use radium::Radium;
+
+#[cfg(feature = "atomic")]
+use core::sync::atomic;
 
 /** Access interface for shared/mutable memory access.
 
 `&BitSlice` and `&mut BitSlice` contexts must route through their `Access`
 associated type, which implements this trait, in order to perform *any* access
-to underlying memory. This trait extends the `Radium` element-wise shared
+to underlying memory. This trait extends the `Shared` element-wise shared
 mutable access with single-bit operations suited for use by `BitSlice`.
 **/
-pub trait BitAccess<T>: Debug + Radium<T> + Sized
-where T: BitStore + BitOps + Sized
+pub trait BitAccess<T>: Debug + Shared<T> + Sized
+where T: BitStore
 {
 	/// Set a single bit in an element low.
 	///
@@ -134,7 +135,26 @@ where T: BitStore + BitOps + Sized
 	#[inline]
 	fn get<O>(&self, place: BitIdx<T>) -> bool
 	where O: BitOrder {
-		<Self as BitAccess<T>>::load(&self) & *O::mask(place) != T::FALSE
+		self.get_with::<O>(place, Ordering::Relaxed)
+	}
+
+	/// Retrieve a single bit from an element, with a given memory ordering.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A `BitOrder` implementation which translates `place` into a
+	///   usable bit-mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `place`: A semantic bit index in the `self` element.
+	/// - `order`: The memory ordering of the load. This must be valid for a
+	///   load.
+	#[inline]
+	fn get_with<O>(&self, place: BitIdx<T>, order: Ordering) -> bool
+	where O: BitOrder {
+		Shared::load(self, order) & *O::mask(place) != T::FALSE
 	}
 
 	/// Set a single bit in an element to some value.
@@ -161,6 +181,151 @@ where T: BitStore + BitOps + Sized
 		}
 	}
 
+	/// Set a single bit in an element to some value, with a given memory
+	/// ordering.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A `BitOrder` implementation which translates `place` into a
+	///   usable bit-mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `place`: A semantic bit index in the `self` element.
+	/// - `value`: The value to which the bit controlled by `place` shall be
+	///   set.
+	/// - `order`: The memory ordering of the read/modify/write operation.
+	#[inline]
+	fn set_with<O>(&self, place: BitIdx<T>, value: bool, order: Ordering)
+	where O: BitOrder {
+		let mask = *O::mask(place);
+		if value {
+			self.fetch_or(mask, order);
+		}
+		else {
+			self.fetch_and(!mask, order);
+		}
+	}
+
+	/// Set a single bit in an element to some value, returning its previous
+	/// value.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A `BitOrder` implementation which translates `place` into a
+	///   usable bit-mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `place`: A semantic bit index in the `self` element.
+	/// - `value`: The value to which the bit controlled by `place` shall be
+	///   set.
+	/// - `order`: The memory ordering of the read/modify/write operation.
+	///
+	/// # Returns
+	///
+	/// The value of the bit controlled by `place` immediately before the
+	/// write.
+	#[inline]
+	fn fetch_set<O>(
+		&self,
+		place: BitIdx<T>,
+		value: bool,
+		order: Ordering,
+	) -> bool
+	where
+		O: BitOrder,
+	{
+		let mask = *O::mask(place);
+		let prev = if value {
+			self.fetch_or(mask, order)
+		}
+		else {
+			self.fetch_and(!mask, order)
+		};
+		prev & mask != T::FALSE
+	}
+
+	/// Invert a single bit in an element, returning its previous value.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A `BitOrder` implementation which translates `place` into a
+	///   usable bit-mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `place`: A semantic bit index in the `self` element.
+	/// - `order`: The memory ordering of the read/modify/write operation.
+	///
+	/// # Returns
+	///
+	/// The value of the bit controlled by `place` immediately before the
+	/// inversion.
+	#[inline]
+	fn fetch_invert<O>(&self, place: BitIdx<T>, order: Ordering) -> bool
+	where O: BitOrder {
+		let mask = *O::mask(place);
+		self.fetch_xor(mask, order) & mask != T::FALSE
+	}
+
+	/// Set a single bit in an element to `new` only if it is currently
+	/// `current`.
+	///
+	/// A single bit has only two states, so this never needs a compare/swap
+	/// loop: setting a bit that is already set, or clearing a bit that is
+	/// already clear, leaves the element unchanged. The comparison and the
+	/// write are therefore one `fetch_or` or `fetch_and` operation.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: A `BitOrder` implementation which translates `place` into a
+	///   usable bit-mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A shared reference to underlying memory.
+	/// - `place`: A semantic bit index in the `self` element.
+	/// - `current`: The value the bit must have for the write to occur.
+	/// - `new`: The value to write into the bit.
+	/// - `order`: The memory ordering of the read/modify/write operation.
+	///
+	/// # Returns
+	///
+	/// `Ok(current)` if the bit had the value `current` and now has the value
+	/// `new`; `Err(!current)` if the bit did not have the value `current` and
+	/// was left unchanged.
+	#[inline]
+	fn compare_exchange_bit<O>(
+		&self,
+		place: BitIdx<T>,
+		current: bool,
+		new: bool,
+		order: Ordering,
+	) -> Result<bool, bool>
+	where
+		O: BitOrder,
+	{
+		let mask = *O::mask(place);
+		let prev = match (current, new) {
+			//  Writing the value a bit must already have is a no-op, but the
+			//  read still participates in `order`.
+			(false, false) | (true, true) => self.fetch_or(T::FALSE, order),
+			(false, true) => self.fetch_or(mask, order),
+			(true, false) => self.fetch_and(!mask, order),
+		};
+		let prev = prev & mask != T::FALSE;
+		if prev == current {
+			Ok(prev)
+		}
+		else {
+			Err(prev)
+		}
+	}
+
 	/// Read a value out of a contended memory element and into a local scope.
 	///
 	/// # Parameters
@@ -172,7 +337,7 @@ where T: BitStore + BitOps + Sized
 	/// The value of `*self`. This value is only useful when access is
 	/// uncontended by multiple `BitSlice` regions.
 	fn load(&self) -> T {
-		Radium::load(self, Ordering::Relaxed)
+		Shared::load(self, Ordering::Relaxed)
 	}
 
 	/// Stores a value into a contended memory element.
@@ -182,7 +347,7 @@ where T: BitStore + BitOps + Sized
 	/// - `&self`: A shared reference to underlying memory.
 	/// - `value`: The new value to write into `*self`.
 	fn store(&self, value: T) {
-		Radium::store(self, value, Ordering::Relaxed)
+		Shared::store(self, value, Ordering::Relaxed)
 	}
 
 	/// Converts a slice of `BitAccess` to a mutable slice of `BitStore`.
@@ -198,7 +363,159 @@ where T: BitStore + BitOps + Sized
 
 impl<T, R> BitAccess<T> for R
 where
-	T: BitStore + BitOps,
-	R: Debug + Radium<T>,
+	T: BitStore,
+	R: Debug + Shared<T>,
 {
 }
+
+/** Element-wise shared-mutable access to a memory element.
+
+This is the subset of the `Radium` trait that `BitAccess` requires. `Radium`
+only covers the types that have an atomic counterpart, and marks them with a
+trait that this crate cannot implement for other types, so `bitvec` routes
+through this trait in order to also support `Cell<u128>`. Every other
+implementation forwards to `Radium`.
+**/
+pub trait Shared<T> {
+	/// Loads the element.
+	fn load(&self, order: Ordering) -> T;
+
+	/// Stores a new value into the element.
+	fn store(&self, value: T, order: Ordering);
+
+	/// Performs a bitwise AND on the element, returning its previous value.
+	fn fetch_and(&self, value: T, order: Ordering) -> T;
+
+	/// Performs a bitwise OR on the element, returning its previous value.
+	fn fetch_or(&self, value: T, order: Ordering) -> T;
+
+	/// Performs a bitwise XOR on the element, returning its previous value.
+	fn fetch_xor(&self, value: T, order: Ordering) -> T;
+
+	/// Stores `new` into the element if it is currently `current`. This may
+	/// fail spuriously, and so should be called in a loop.
+	///
+	/// # Returns
+	///
+	/// `Ok` with the previous value if the store occurred, or `Err` with the
+	/// current value if it did not.
+	fn compare_exchange_weak(
+		&self,
+		current: T,
+		new: T,
+		success: Ordering,
+		failure: Ordering,
+	) -> Result<T, T>;
+}
+
+/// Forwards `Shared` to `Radium` for a fundamental and its access types.
+macro_rules! shared {
+	($($t:ty => $atom:ty;)*) => { $(
+		#[cfg(feature = "atomic")]
+		shared!(@impl $t, $atom);
+
+		shared!(@impl $t, Cell<$t>);
+	)* };
+
+	(@impl $t:ty, $r:ty) => {
+		impl Shared<$t> for $r {
+			#[inline(always)]
+			fn load(&self, order: Ordering) -> $t {
+				Radium::load(self, order)
+			}
+
+			#[inline(always)]
+			fn store(&self, value: $t, order: Ordering) {
+				Radium::store(self, value, order)
+			}
+
+			#[inline(always)]
+			fn fetch_and(&self, value: $t, order: Ordering) -> $t {
+				Radium::fetch_and(self, value, order)
+			}
+
+			#[inline(always)]
+			fn fetch_or(&self, value: $t, order: Ordering) -> $t {
+				Radium::fetch_or(self, value, order)
+			}
+
+			#[inline(always)]
+			fn fetch_xor(&self, value: $t, order: Ordering) -> $t {
+				Radium::fetch_xor(self, value, order)
+			}
+
+			#[inline(always)]
+			fn compare_exchange_weak(
+				&self,
+				current: $t,
+				new: $t,
+				success: Ordering,
+				failure: Ordering,
+			) -> Result<$t, $t>
+			{
+				Radium::compare_exchange_weak(
+					self, current, new, success, failure,
+				)
+			}
+		}
+	};
+}
+
+shared! {
+	u8 => atomic::AtomicU8;
+	u16 => atomic::AtomicU16;
+	u32 => atomic::AtomicU32;
+	usize => atomic::AtomicUsize;
+}
+
+#[cfg(target_pointer_width = "64")]
+shared! {
+	u64 => atomic::AtomicU64;
+}
+
+/// `u128` has no atomic counterpart, so it is always accessed through `Cell`.
+impl Shared<u128> for Cell<u128> {
+	#[inline(always)]
+	fn load(&self, _: Ordering) -> u128 {
+		self.get()
+	}
+
+	#[inline(always)]
+	fn store(&self, value: u128, _: Ordering) {
+		self.set(value)
+	}
+
+	#[inline(always)]
+	fn fetch_and(&self, value: u128, _: Ordering) -> u128 {
+		self.replace(self.get() & value)
+	}
+
+	#[inline(always)]
+	fn fetch_or(&self, value: u128, _: Ordering) -> u128 {
+		self.replace(self.get() | value)
+	}
+
+	#[inline(always)]
+	fn fetch_xor(&self, value: u128, _: Ordering) -> u128 {
+		self.replace(self.get() ^ value)
+	}
+
+	#[inline]
+	fn compare_exchange_weak(
+		&self,
+		current: u128,
+		new: u128,
+		_: Ordering,
+		_: Ordering,
+	) -> Result<u128, u128>
+	{
+		let prev = self.get();
+		if prev == current {
+			self.set(new);
+			Ok(prev)
+		}
+		else {
+			Err(prev)
+		}
+	}
+}
@@ -0,0 +1,219 @@
+/*! `BitArray` structure
+
+This module holds the type for an owned, fixed-size bit sequence that is held by
+value. Unlike `BitBox` and `BitVec`, it does not require an allocator, and so it
+is available in `#![no_std]` environments.
+!*/
+
+use crate::{
+	order::{
+		BitOrder,
+		Local,
+	},
+	slice::{
+		AsBits,
+		BitSlice,
+	},
+};
+
+use core::marker::PhantomData;
+
+/** An owned, fixed-size bit sequence, held by value.
+
+This type is a wrapper over an element or an array of elements, which it always
+views as a `BitSlice` spanning every bit of its storage. It does not borrow or
+allocate, so it can be placed on the stack, in a `static`, or inside other
+structures, and it is `Copy` whenever its storage is.
+
+`BitArray` dereferences to `BitSlice`, and so has all of its methods. It has no
+length field: its length is always the number of bits in its storage, so
+`BitArray<_, [u8; 3]>` always has twenty-four bits.
+
+# Type Parameters
+
+- `O: BitOrder`: An implementor of the [`BitOrder`] trait. This type is used to
+  convert semantic indices into concrete bit positions in elements, and store or
+  retrieve bit values from the storage type.
+- `V: AsBits`: The storage held by value. This is a [`BitStore`] fundamental, or
+  an array of up to thirty-two of them.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut arr: BitArray<Msb0, [u8; 2]> = BitArray::new([0; 2]);
+arr.set(3, true);
+arr[8 ..].set_all(true);
+assert_eq!(arr.into_inner(), [0x10, 0xFF]);
+```
+
+[`BitOrder`]: ../order/trait.BitOrder.html
+[`BitStore`]: ../store/trait.BitStore.html
+**/
+#[repr(transparent)]
+pub struct BitArray<O = Local, V = [usize; 1]>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	_order: PhantomData<O>,
+	data: V,
+}
+
+impl<O, V> BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	/// Wraps a value in a `BitArray`.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The element or array of elements that the `BitArray` will
+	///   hold.
+	///
+	/// # Returns
+	///
+	/// A `BitArray` whose bits are the bits of `data`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let arr = BitArray::<Lsb0, _>::new([1u16, 0x8000]);
+	/// assert_eq!(arr.len(), 32);
+	/// assert!(arr[0]);
+	/// assert!(arr[31]);
+	/// ```
+	#[inline]
+	pub fn new(data: V) -> Self {
+		Self {
+			_order: PhantomData,
+			data,
+		}
+	}
+
+	/// Removes the `BitArray` wrapper, returning the held value.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The element or array of elements that the `BitArray` held.
+	#[inline]
+	pub fn into_inner(self) -> V {
+		self.data
+	}
+
+	/// Accesses the `BitSlice<O, T>` view of the held value.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `BitSlice` spanning every bit of the held value.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<O, V::Store> {
+		self.data.bits::<O>()
+	}
+
+	/// Accesses the `BitSlice<O, T>` view of the held value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A `BitSlice` spanning every bit of the held value.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<O, V::Store> {
+		self.data.bits_mut::<O>()
+	}
+
+	/// Accesses the held value as an element slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice of every element in the held value.
+	#[inline]
+	pub fn as_slice(&self) -> &[V::Store] {
+		self.as_bitslice().as_slice()
+	}
+
+	/// Accesses the held value as an element slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A slice of every element in the held value.
+	#[inline]
+	pub fn as_mut_slice(&mut self) -> &mut [V::Store] {
+		self.as_mut_bitslice().as_mut_slice()
+	}
+}
+
+mod ops;
+mod traits;
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	#[test]
+	fn layout() {
+		assert_eq!(
+			core::mem::size_of::<BitArray<Msb0, [u16; 3]>>(),
+			core::mem::size_of::<[u16; 3]>(),
+		);
+		let arr = BitArray::<Lsb0, u32>::new(!0);
+		assert_eq!(arr.len(), 32);
+		assert!(arr.all());
+		assert!(BitArray::<Local, [u8; 0]>::new([]).is_empty());
+	}
+
+	#[test]
+	fn value_semantics() {
+		let mut a = bitarr![Msb0, u8; 1, 0, 1, 1];
+		let b = a;
+		a.set(1, true);
+		assert_eq!(a.as_slice(), &[0xF0]);
+		assert_eq!(b.as_slice(), &[0xB0]);
+		assert_ne!(a, b);
+		assert!(a > b);
+		assert_eq!(b, bits![Msb0, u8; 1, 0, 1, 1, 0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn operators() {
+		let a = bitarr![Lsb0, u8; 1, 1, 0, 0];
+		let b = bitarr![Lsb0, u8; 1, 0, 1, 0];
+		assert_eq!((a & b.iter().copied()).into_inner(), [0b0001]);
+		assert_eq!((a | b.iter().copied()).into_inner(), [0b0111]);
+		assert_eq!((a ^ b.iter().copied()).into_inner(), [0b0110]);
+		assert_eq!((!a).into_inner(), [!0b0011]);
+		assert_eq!((a << 1).into_inner(), [0b0001]);
+		assert_eq!((a >> 2).into_inner(), [0b1100]);
+	}
+
+	#[test]
+	fn fields() {
+		let mut arr = BitArray::<Msb0, [u8; 4]>::default();
+		arr[4 .. 20].store(0x1234u16);
+		assert_eq!(arr[4 .. 20].load::<u16>(), 0x1234);
+		assert_eq!(arr.load::<u32>(), arr.as_bitslice().load::<u32>());
+	}
+}
@@ -0,0 +1,338 @@
+//! Operator trait implementations.
+
+use crate::{
+	array::BitArray,
+	order::BitOrder,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
+};
+
+use core::ops::{
+	BitAnd,
+	BitAndAssign,
+	BitOr,
+	BitOrAssign,
+	BitXor,
+	BitXorAssign,
+	Deref,
+	DerefMut,
+	Index,
+	IndexMut,
+	Not,
+	Range,
+	RangeFrom,
+	RangeFull,
+	RangeInclusive,
+	RangeTo,
+	RangeToInclusive,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
+};
+
+impl<O, V, I> BitAnd<I> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	I: IntoIterator<Item = bool>,
+{
+	type Output = Self;
+
+	fn bitand(mut self, rhs: I) -> Self::Output {
+		self &= rhs;
+		self
+	}
+}
+
+impl<O, V, I> BitAndAssign<I> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	I: IntoIterator<Item = bool>,
+{
+	fn bitand_assign(&mut self, rhs: I) {
+		self.as_mut_bitslice().bitand_assign(rhs);
+	}
+}
+
+impl<O, V, I> BitOr<I> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	I: IntoIterator<Item = bool>,
+{
+	type Output = Self;
+
+	fn bitor(mut self, rhs: I) -> Self::Output {
+		self |= rhs;
+		self
+	}
+}
+
+impl<O, V, I> BitOrAssign<I> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	I: IntoIterator<Item = bool>,
+{
+	fn bitor_assign(&mut self, rhs: I) {
+		self.as_mut_bitslice().bitor_assign(rhs);
+	}
+}
+
+impl<O, V, I> BitXor<I> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	I: IntoIterator<Item = bool>,
+{
+	type Output = Self;
+
+	fn bitxor(mut self, rhs: I) -> Self::Output {
+		self ^= rhs;
+		self
+	}
+}
+
+impl<O, V, I> BitXorAssign<I> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	I: IntoIterator<Item = bool>,
+{
+	fn bitxor_assign(&mut self, rhs: I) {
+		self.as_mut_bitslice().bitxor_assign(rhs);
+	}
+}
+
+impl<O, V> Deref for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Target = BitSlice<O, V::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V> DerefMut for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O, V> Index<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = bool;
+
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.as_bitslice()[index]
+	}
+}
+
+impl<O, V> Index<Range<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = BitSlice<O, V::Store>;
+
+	fn index(&self, range: Range<usize>) -> &Self::Output {
+		&self.as_bitslice()[range]
+	}
+}
+
+impl<O, V> IndexMut<Range<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn index_mut(&mut self, range: Range<usize>) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[range]
+	}
+}
+
+impl<O, V> Index<RangeFrom<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = BitSlice<O, V::Store>;
+
+	fn index(&self, range: RangeFrom<usize>) -> &Self::Output {
+		&self.as_bitslice()[range]
+	}
+}
+
+impl<O, V> IndexMut<RangeFrom<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn index_mut(&mut self, range: RangeFrom<usize>) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[range]
+	}
+}
+
+impl<O, V> Index<RangeFull> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = BitSlice<O, V::Store>;
+
+	fn index(&self, _: RangeFull) -> &Self::Output {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V> IndexMut<RangeFull> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O, V> Index<RangeInclusive<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = BitSlice<O, V::Store>;
+
+	fn index(&self, range: RangeInclusive<usize>) -> &Self::Output {
+		&self.as_bitslice()[range]
+	}
+}
+
+impl<O, V> IndexMut<RangeInclusive<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn index_mut(&mut self, range: RangeInclusive<usize>) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[range]
+	}
+}
+
+impl<O, V> Index<RangeTo<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = BitSlice<O, V::Store>;
+
+	fn index(&self, range: RangeTo<usize>) -> &Self::Output {
+		&self.as_bitslice()[range]
+	}
+}
+
+impl<O, V> IndexMut<RangeTo<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn index_mut(&mut self, range: RangeTo<usize>) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[range]
+	}
+}
+
+impl<O, V> Index<RangeToInclusive<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = BitSlice<O, V::Store>;
+
+	fn index(&self, range: RangeToInclusive<usize>) -> &Self::Output {
+		&self.as_bitslice()[range]
+	}
+}
+
+impl<O, V> IndexMut<RangeToInclusive<usize>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn index_mut(
+		&mut self,
+		range: RangeToInclusive<usize>,
+	) -> &mut Self::Output
+	{
+		&mut self.as_mut_bitslice()[range]
+	}
+}
+
+impl<O, V> Not for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = Self;
+
+	fn not(mut self) -> Self::Output {
+		let _ = self.as_mut_bitslice().not();
+		self
+	}
+}
+
+impl<O, V> Shl<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = Self;
+
+	fn shl(mut self, shamt: usize) -> Self::Output {
+		self <<= shamt;
+		self
+	}
+}
+
+impl<O, V> ShlAssign<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn shl_assign(&mut self, shamt: usize) {
+		self.as_mut_bitslice().shl_assign(shamt);
+	}
+}
+
+impl<O, V> Shr<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type Output = Self;
+
+	fn shr(mut self, shamt: usize) -> Self::Output {
+		self >>= shamt;
+		self
+	}
+}
+
+impl<O, V> ShrAssign<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn shr_assign(&mut self, shamt: usize) {
+		self.as_mut_bitslice().shr_assign(shamt);
+	}
+}
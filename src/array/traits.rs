@@ -0,0 +1,324 @@
+/*! General trait implementations for `BitArray`
+
+The operator traits are defined in the `ops` module.
+!*/
+
+use crate::{
+	array::BitArray,
+	order::BitOrder,
+	slice::{
+		AsBits,
+		BitSlice,
+		Iter,
+	},
+	store::BitStore,
+};
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp::Ordering,
+	fmt::{
+		self,
+		Binary,
+		Debug,
+		Display,
+		Formatter,
+		LowerHex,
+		Octal,
+		UpperHex,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+impl<O, V> Borrow<BitSlice<O, V::Store>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn borrow(&self) -> &BitSlice<O, V::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V> BorrowMut<BitSlice<O, V::Store>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn borrow_mut(&mut self) -> &mut BitSlice<O, V::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O, V> Clone for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits + Clone,
+{
+	fn clone(&self) -> Self {
+		Self::new(self.data.clone())
+	}
+}
+
+/// `BitArray` is `Copy` whenever its storage is, regardless of its ordering.
+impl<O, V> Copy for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits + Copy,
+{
+}
+
+impl<O, V> Eq for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+}
+
+impl<O, V> Ord for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.as_bitslice().cmp(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitArray<C, D>> for BitArray<A, B>
+where
+	A: BitOrder,
+	B: AsBits,
+	C: BitOrder,
+	D: AsBits,
+{
+	fn eq(&self, rhs: &BitArray<C, D>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitSlice<C, D>> for BitArray<A, B>
+where
+	A: BitOrder,
+	B: AsBits,
+	C: BitOrder,
+	D: BitStore,
+{
+	fn eq(&self, rhs: &BitSlice<C, D>) -> bool {
+		self.as_bitslice().eq(rhs)
+	}
+}
+
+impl<A, B, C, D> PartialEq<&BitSlice<C, D>> for BitArray<A, B>
+where
+	A: BitOrder,
+	B: AsBits,
+	C: BitOrder,
+	D: BitStore,
+{
+	fn eq(&self, rhs: &&BitSlice<C, D>) -> bool {
+		self.as_bitslice().eq(*rhs)
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitArray<C, D>> for BitSlice<A, B>
+where
+	A: BitOrder,
+	B: BitStore,
+	C: BitOrder,
+	D: AsBits,
+{
+	fn eq(&self, rhs: &BitArray<C, D>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialOrd<BitArray<C, D>> for BitArray<A, B>
+where
+	A: BitOrder,
+	B: AsBits,
+	C: BitOrder,
+	D: AsBits,
+{
+	fn partial_cmp(&self, rhs: &BitArray<C, D>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialOrd<BitSlice<C, D>> for BitArray<A, B>
+where
+	A: BitOrder,
+	B: AsBits,
+	C: BitOrder,
+	D: BitStore,
+{
+	fn partial_cmp(&self, rhs: &BitSlice<C, D>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs)
+	}
+}
+
+impl<A, B, C, D> PartialOrd<BitArray<C, D>> for BitSlice<A, B>
+where
+	A: BitOrder,
+	B: BitStore,
+	C: BitOrder,
+	D: AsBits,
+{
+	fn partial_cmp(&self, rhs: &BitArray<C, D>) -> Option<Ordering> {
+		self.partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<O, V> AsMut<BitSlice<O, V::Store>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn as_mut(&mut self) -> &mut BitSlice<O, V::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O, V> AsMut<[V::Store]> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn as_mut(&mut self) -> &mut [V::Store] {
+		self.as_mut_slice()
+	}
+}
+
+impl<O, V> AsRef<BitSlice<O, V::Store>> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn as_ref(&self) -> &BitSlice<O, V::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V> AsRef<[V::Store]> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn as_ref(&self) -> &[V::Store] {
+		self.as_slice()
+	}
+}
+
+impl<O, V> From<V> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn from(data: V) -> Self {
+		Self::new(data)
+	}
+}
+
+impl<O, V> Default for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits + Default,
+{
+	fn default() -> Self {
+		Self::new(V::default())
+	}
+}
+
+impl<O, V> Binary for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Binary::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> Debug for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("BitArray<")?;
+		fmt.write_str(O::TYPENAME)?;
+		fmt.write_str(", ")?;
+		fmt.write_str(V::Store::TYPENAME)?;
+		fmt.write_str("> ")?;
+		Display::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> Display for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> LowerHex for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		LowerHex::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> Octal for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Octal::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> UpperHex for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		UpperHex::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> Hash for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<'a, O, V> IntoIterator for &'a BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+{
+	type IntoIter = Iter<'a, O, V::Store>;
+	type Item = &'a bool;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_bitslice().into_iter()
+	}
+}
@@ -0,0 +1,421 @@
+/*! `ConcurrentBitmap` structure
+
+This module holds a fixed-size table of slots, one bit per slot, which many
+threads can claim and release at once through shared references. A set bit
+marks its slot as claimed.
+
+Claiming a slot scans the table an element at a time, skipping full elements
+with a single load, and sets the first clear bit it finds with a compare/exchange
+on the element's atomic access type. If another thread changed the element
+first, the claim retries within that element before moving on. No lock is ever
+taken.
+!*/
+
+#![cfg(all(feature = "alloc", feature = "atomic"))]
+
+use crate::{
+	access::{
+		BitAccess,
+		Shared,
+	},
+	boxed::BitBox,
+	indices::{
+		BitIdx,
+		Indexable,
+	},
+	order::{
+		BitOrder,
+		Local,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+	sync::atomic::Ordering,
+};
+
+use either::Either;
+
+/** A fixed-size table of slots that threads claim and release without locks.
+
+Every operation takes `&self`, so a bitmap can be shared between threads
+through a plain reference or an `Arc`, and used as the free list of an arena or
+pool.
+
+Claiming a slot synchronizes with the release that last freed it, so a thread
+that claims a slot observes everything the previous owner wrote before it
+released the slot.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of bits within the underlying storage elements.
+  This decides which slot is “first” within an element.
+- `T: BitStore`: The storage element type. The bitmap is only `Sync` when `T`
+  has an atomic access type, which excludes `u128`.
+
+# Examples
+
+```rust
+use bitvec::bitmap::ConcurrentBitmap;
+
+let slots: ConcurrentBitmap = ConcurrentBitmap::new(3);
+assert_eq!(slots.claim(), Some(0));
+assert_eq!(slots.claim(), Some(1));
+assert_eq!(slots.claim(), Some(2));
+assert_eq!(slots.claim(), None);
+
+assert!(slots.release(1));
+assert_eq!(slots.occupancy(), 2);
+assert_eq!(slots.claim(), Some(1));
+```
+**/
+pub struct ConcurrentBitmap<O = Local, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bits: BitBox<O, T>,
+	/// The bitmap writes through `&self`, so it may only be `Sync` when the
+	/// access type is. For the same reason, the box is never lent out as a
+	/// `&BitSlice`: every read goes to the elements through `T::Access`.
+	_access: PhantomData<T::Access>,
+}
+
+impl<O, T> ConcurrentBitmap<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs a bitmap of unclaimed slots.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of slots in the bitmap.
+	pub fn new(len: usize) -> Self {
+		Self::from_bitbox(BitVec::repeat(false, len).into_boxed_bitslice())
+	}
+
+	/// Wraps a bit box as a bitmap, in which its set bits are claimed slots.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: A bit box, whose set bits mark slots that are already
+	///   claimed.
+	pub fn from_bitbox(bits: BitBox<O, T>) -> Self {
+		Self {
+			bits,
+			_access: PhantomData,
+		}
+	}
+
+	/// Unwraps the bitmap into its underlying bit box.
+	///
+	/// # Returns
+	///
+	/// A bit box in which the bit of each claimed slot is set.
+	pub fn into_bitbox(self) -> BitBox<O, T> {
+		self.bits
+	}
+
+	/// Counts the slots in the bitmap, whether claimed or not.
+	pub fn len(&self) -> usize {
+		self.bits.bitptr().len()
+	}
+
+	/// Tests if the bitmap has no slots.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Counts the claimed slots.
+	///
+	/// This counts the set bits an element at a time. Other threads may claim
+	/// and release slots during the count, so the result is only exact when
+	/// the bitmap is not contended.
+	pub fn occupancy(&self) -> usize {
+		let count = |elt: &T::Access, mask: T| {
+			(Shared::load(elt, Ordering::Relaxed) & mask).count_ones()
+		};
+		match self.bits.bitptr().domain().splat() {
+			Either::Right((h, e, t)) => count(e, O::mask_range(h, t)),
+			Either::Left((h, b, t)) => {
+				let head = h.map_or(0, |(h, head)| {
+					count(head, O::mask_range(h, T::BITS.tail()))
+				});
+				let body = b
+					.unwrap_or(&[])
+					.iter()
+					.map(|elt| count(elt, !T::FALSE))
+					.sum::<usize>();
+				let tail = t.map_or(0, |(tail, t)| {
+					count(tail, O::mask_range(0u8.idx(), t))
+				});
+				head + body + tail
+			},
+		}
+	}
+
+	/// Tests if a slot is claimed.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The slot to test.
+	///
+	/// # Returns
+	///
+	/// Whether the slot at `index` is claimed.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than `self.len()`.
+	pub fn is_claimed(&self, index: usize) -> bool {
+		let (elt, bit) = self.locate(index);
+		elt.get_with::<O>(bit, Ordering::Acquire)
+	}
+
+	/// Claims the first unclaimed slot.
+	///
+	/// # Returns
+	///
+	/// The index of the slot that this call claimed, or `None` if every slot
+	/// was claimed when the scan passed it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::bitmap::ConcurrentBitmap;
+	/// use bitvec::prelude::*;
+	///
+	/// let slots = ConcurrentBitmap::from_bitbox(
+	///     bitbox![Msb0, u8; 1, 1, 0, 1, 0],
+	/// );
+	/// assert_eq!(slots.claim(), Some(2));
+	/// assert_eq!(slots.claim(), Some(4));
+	/// assert_eq!(slots.claim(), None);
+	/// ```
+	pub fn claim(&self) -> Option<usize> {
+		match self.bits.bitptr().domain().splat() {
+			Either::Right((h, e, t)) => {
+				claim_in::<O, T>(e, O::mask_range(h, t))
+					.map(|n| (*n - *h) as usize)
+			},
+			Either::Left((h, b, t)) => {
+				//  The slot index of the first bit in the next element.
+				let mut base = 0usize;
+				if let Some((h, head)) = h {
+					let mask = O::mask_range(h, T::BITS.tail());
+					if let Some(n) = claim_in::<O, T>(head, mask) {
+						return Some((*n - *h) as usize);
+					}
+					base += (T::BITS - *h) as usize;
+				}
+				if let Some(body) = b {
+					for elt in body {
+						if let Some(n) = claim_in::<O, T>(elt, !T::FALSE) {
+							return Some(base + *n as usize);
+						}
+						base += T::BITS as usize;
+					}
+				}
+				if let Some((tail, t)) = t {
+					let mask = O::mask_range(0u8.idx(), t);
+					return claim_in::<O, T>(tail, mask)
+						.map(|n| base + *n as usize);
+				}
+				None
+			},
+		}
+	}
+
+	/// Releases a slot, so that a later claim may take it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The slot to release.
+	///
+	/// # Returns
+	///
+	/// `true` if the slot was claimed, or `false` if it was already free.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is not less than `self.len()`.
+	pub fn release(&self, index: usize) -> bool {
+		let (elt, bit) = self.locate(index);
+		elt.fetch_set::<O>(bit, false, Ordering::Release)
+	}
+
+	/// Finds the element and bit that hold a slot.
+	fn locate(&self, index: usize) -> (&T::Access, BitIdx<T>) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bits.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		(unsafe { &*bitptr.pointer().a().offset(elt) }, bit)
+	}
+}
+
+impl<O, T> Debug for ConcurrentBitmap<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("ConcurrentBitmap")
+			.field("len", &self.len())
+			.field("occupancy", &self.occupancy())
+			.finish()
+	}
+}
+
+impl<O, T> From<BitBox<O, T>> for ConcurrentBitmap<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: BitBox<O, T>) -> Self {
+		Self::from_bitbox(bits)
+	}
+}
+
+/// Claims the first clear bit, among the set bits of `mask`, in an element.
+///
+/// # Returns
+///
+/// The index of the bit that this call set, or `None` if every bit in `mask`
+/// was already set.
+fn claim_in<O, T>(elt: &T::Access, mask: T) -> Option<BitIdx<T>>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let mut cur = Shared::load(elt, Ordering::Relaxed);
+	loop {
+		let idx = O::first_one(!cur & mask)?;
+		match elt.compare_exchange_weak(
+			cur,
+			cur | *O::mask(idx),
+			Ordering::Acquire,
+			Ordering::Relaxed,
+		) {
+			Ok(_) => return Some(idx),
+			Err(now) => cur = now,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::order::{
+		Lsb0,
+		Msb0,
+	};
+
+	#[test]
+	fn claims() {
+		let slots = ConcurrentBitmap::<Lsb0, u8>::new(20);
+		for n in 0 .. 20 {
+			assert_eq!(slots.claim(), Some(n));
+		}
+		assert_eq!(slots.claim(), None);
+		assert_eq!(slots.occupancy(), 20);
+
+		assert!(slots.release(17));
+		assert!(slots.release(3));
+		assert!(!slots.release(3));
+		assert!(!slots.is_claimed(3));
+		assert!(slots.is_claimed(4));
+		assert_eq!(slots.occupancy(), 18);
+		assert_eq!(slots.claim(), Some(3));
+		assert_eq!(slots.claim(), Some(17));
+		assert_eq!(slots.claim(), None);
+	}
+
+	#[test]
+	fn claims_offset() {
+		let mut bits = bitbox![Msb0, u16; 0; 40];
+		bits.set(0, true);
+		bits.set(20, true);
+		//  A box whose first slot is not at the front of its first element.
+		let boxed = BitBox::from_bitslice(&bits[3 ..]);
+		let slots = ConcurrentBitmap::from(boxed);
+		assert_eq!(slots.len(), 37);
+		assert_eq!(slots.occupancy(), 1);
+		for n in (0 .. 37).filter(|&n| n != 17) {
+			assert_eq!(slots.claim(), Some(n));
+		}
+		assert_eq!(slots.claim(), None);
+		assert_eq!(slots.occupancy(), 37);
+
+		let wide = ConcurrentBitmap::<Lsb0, u128>::new(130);
+		assert_eq!(wide.claim(), Some(0));
+		assert_eq!(wide.occupancy(), 1);
+		assert!(wide.release(0));
+		assert!(!wide.is_claimed(0));
+		assert_eq!(wide.into_bitbox().count_ones(), 0);
+	}
+
+	/// Threads that race to claim slots never share one, and every slot they
+	/// release is claimed again.
+	#[test]
+	#[cfg(feature = "std")]
+	fn stress() {
+		use core::sync::atomic::AtomicUsize;
+		use std::{
+			sync::Arc,
+			thread,
+			vec::Vec,
+		};
+
+		const SLOTS: usize = 100;
+		const THREADS: usize = 8;
+		const ROUNDS: usize = 2000;
+
+		let slots = Arc::new(ConcurrentBitmap::<Lsb0, u8>::new(SLOTS));
+		let owners = Arc::new(
+			(0 .. SLOTS).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>(),
+		);
+		let threads = (0 .. THREADS)
+			.map(|_| {
+				let slots = slots.clone();
+				let owners = owners.clone();
+				thread::spawn(move || {
+					let mut held = Vec::new();
+					for round in 0 .. ROUNDS {
+						//  Hold up to nine slots, then release half of them.
+						if let Some(n) = slots.claim() {
+							let prev = owners[n].fetch_add(1, Ordering::Relaxed);
+							assert_eq!(prev, 0, "slot {} claimed twice", n);
+							held.push(n);
+						}
+						if held.len() > 8 || round % 7 == 0 {
+							for n in held.drain(.. held.len() / 2) {
+								owners[n].fetch_sub(1, Ordering::Relaxed);
+								assert!(slots.release(n));
+							}
+						}
+					}
+					for n in held {
+						owners[n].fetch_sub(1, Ordering::Relaxed);
+						assert!(slots.release(n));
+					}
+				})
+			})
+			.collect::<Vec<_>>();
+		for thread in threads {
+			thread.join().unwrap();
+		}
+		assert_eq!(slots.occupancy(), 0);
+		assert!(owners.iter().all(|n| n.load(Ordering::Relaxed) == 0));
+	}
+}
@@ -354,10 +354,12 @@ where
 {
 }
 
-/// `&BitBox` is safe to move across thread boundaries.
+/// `&BitBox` is safe to move across thread boundaries when its elements are
+/// accessed atomically, as it lends out the `&BitSlice` that it owns.
 unsafe impl<O, T> Sync for BitBox<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
+	T::Access: Sync,
 {
 }
@@ -4,20 +4,367 @@ The domains governed by `BitSlice` and owned-variant handles have different
 representative states depending on the span of governed elements and live bits.
 
 This module provides representations of the domain states for ease of use by
-handle operations.
+handle operations, and the public [`Domain`] and [`DomainMut`] views of them,
+which let user code process a `BitSlice` an element at a time.
+
+A `BitSlice` may share its partially-live edge elements with other handles,
+which can write to them at any time. The views therefore give out the edge
+elements only as [`PartialElement`] and [`PartialElementMut`] handles, which
+read and write through the shared-mutable access type and touch only the live
+bits. The fully-live body elements belong to the slice alone, and are given out
+as ordinary `&[T]` and `&mut [T]` slices.
+
+[`Domain`]: enum.Domain.html
+[`DomainMut`]: enum.DomainMut.html
+[`PartialElement`]: struct.PartialElement.html
+[`PartialElementMut`]: struct.PartialElementMut.html
 !*/
 
 use crate::{
+	access::BitAccess,
 	indices::{
 		BitIdx,
 		BitTail,
+		Indexable,
 	},
+	order::BitOrder,
 	pointer::BitPtr,
 	store::BitStore,
 };
 
+use core::{
+	marker::PhantomData,
+	slice,
+};
+
 use either::Either;
 
+/** A read-only view of the elements under a `BitSlice`.
+
+This is produced by [`BitSlice::domain`], and splits the slice into the
+elements that it fully occupies and the edge elements that it only partially
+occupies.
+
+# Lifetimes
+
+- `'a`: The lifetime of the `BitSlice` borrow.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of the slice, used to mask the edge elements.
+- `T: BitStore`: The type of the elements under the slice.
+
+[`BitSlice::domain`]: ../slice/struct.BitSlice.html#method.domain
+**/
+#[derive(Debug)]
+pub enum Domain<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The slice lies inside one element, and reaches neither of its edges.
+	Enclave(PartialElement<'a, O, T>),
+	/// The slice reaches at least one element edge. It has no body elements
+	/// only when it is empty or covers only partial elements.
+	Region {
+		/// The lowest-addressed element, if the slice does not begin at its
+		/// edge.
+		head: Option<PartialElement<'a, O, T>>,
+		/// The elements that the slice fully occupies.
+		body: &'a [T],
+		/// The highest-addressed element, if the slice does not end at its
+		/// edge.
+		tail: Option<PartialElement<'a, O, T>>,
+	},
+}
+
+impl<'a, O, T> Clone for Domain<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, O, T> Copy for Domain<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+}
+
+impl<'a, O, T> Domain<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// Views the domain of a `BitSlice` pointer.
+	pub(crate) fn new(bitptr: BitPtr<T>) -> Self {
+		match bitptr.domain().splat() {
+			Either::Right((h, e, t)) => {
+				Domain::Enclave(PartialElement::new(e, h, t))
+			},
+			Either::Left((h, b, t)) => Domain::Region {
+				head: h.map(|(h, e)| PartialElement::new(e, h, T::BITS.tail())),
+				//  The body elements are not shared with any other handle, and
+				//  no `BitSlice` method writes through a shared reference, so
+				//  they cannot change while this view lives.
+				body: match b {
+					Some(b) => unsafe {
+						&*(b as *const [T::Access] as *const [T])
+					},
+					None => &[],
+				},
+				tail: t.map(|(e, t)| PartialElement::new(e, 0u8.idx(), t)),
+			},
+		}
+	}
+}
+
+/** A mutable view of the elements under a `BitSlice`.
+
+This is produced by [`BitSlice::domain_mut`], and splits the slice into the
+elements that it fully occupies and the edge elements that it only partially
+occupies.
+
+# Lifetimes
+
+- `'a`: The lifetime of the `BitSlice` borrow.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of the slice, used to mask the edge elements.
+- `T: BitStore`: The type of the elements under the slice.
+
+[`BitSlice::domain_mut`]: ../slice/struct.BitSlice.html#method.domain_mut
+**/
+#[derive(Debug)]
+pub enum DomainMut<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The slice lies inside one element, and reaches neither of its edges.
+	Enclave(PartialElementMut<'a, O, T>),
+	/// The slice reaches at least one element edge. It has no body elements
+	/// only when it is empty or covers only partial elements.
+	Region {
+		/// The lowest-addressed element, if the slice does not begin at its
+		/// edge.
+		head: Option<PartialElementMut<'a, O, T>>,
+		/// The elements that the slice fully occupies.
+		body: &'a mut [T],
+		/// The highest-addressed element, if the slice does not end at its
+		/// edge.
+		tail: Option<PartialElementMut<'a, O, T>>,
+	},
+}
+
+impl<'a, O, T> DomainMut<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// Views the domain of a `BitSlice` pointer.
+	///
+	/// # Safety
+	///
+	/// The pointer must have come from a `&mut BitSlice` borrowed for `'a`.
+	pub(crate) unsafe fn new(bitptr: BitPtr<T>) -> Self {
+		match bitptr.domain().splat() {
+			Either::Right((h, e, t)) => DomainMut::Enclave(PartialElementMut {
+				inner: PartialElement::new(e, h, t),
+			}),
+			Either::Left((h, b, t)) => {
+				//  The body elements are not shared with any other handle, and
+				//  begin after the head element if there is one.
+				let body = match b {
+					Some(b) => slice::from_raw_parts_mut(
+						bitptr.pointer().w().add(h.is_some() as usize),
+						b.len(),
+					),
+					None => &mut [],
+				};
+				DomainMut::Region {
+					head: h.map(|(h, e)| PartialElementMut {
+						inner: PartialElement::new(e, h, T::BITS.tail()),
+					}),
+					body,
+					tail: t.map(|(e, t)| PartialElementMut {
+						inner: PartialElement::new(e, 0u8.idx(), t),
+					}),
+				}
+			},
+		}
+	}
+}
+
+/** A read-only handle to an element that a `BitSlice` partially occupies.
+
+Other handles may write to the dead bits of the element at any time, so this
+only ever reads the element through its shared-mutable access type.
+
+# Lifetimes
+
+- `'a`: The lifetime of the `BitSlice` borrow.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of the slice, used to mask the live bits.
+- `T: BitStore`: The type of the element.
+**/
+#[derive(Debug)]
+pub struct PartialElement<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	elem: &'a T::Access,
+	head: BitIdx<T>,
+	tail: BitTail<T>,
+	_order: PhantomData<O>,
+}
+
+impl<'a, O, T> PartialElement<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	fn new(elem: &'a T::Access, head: BitIdx<T>, tail: BitTail<T>) -> Self {
+		Self {
+			elem,
+			head,
+			tail,
+			_order: PhantomData,
+		}
+	}
+
+	/// The index of the first live bit in the element.
+	pub fn head(&self) -> BitIdx<T> {
+		self.head
+	}
+
+	/// The index of the first dead bit after the live bits in the element.
+	pub fn tail(&self) -> BitTail<T> {
+		self.tail
+	}
+
+	/// Produces a mask of the live bits in the element.
+	///
+	/// # Returns
+	///
+	/// An element with the bits that the slice occupies set, and all others
+	/// cleared.
+	pub fn mask(&self) -> T {
+		O::mask_range(self.head, self.tail)
+	}
+
+	/// Reads the live bits of the element.
+	///
+	/// # Returns
+	///
+	/// The value of the element, with the bits that the slice does not occupy
+	/// cleared.
+	pub fn load(&self) -> T {
+		self.elem.load() & self.mask()
+	}
+}
+
+impl<'a, O, T> Clone for PartialElement<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, O, T> Copy for PartialElement<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+}
+
+/** A mutable handle to an element that a `BitSlice` partially occupies.
+
+Other handles may write to the dead bits of the element at any time, so this
+only ever accesses the element through its shared-mutable access type, and
+writes only the live bits.
+
+# Lifetimes
+
+- `'a`: The lifetime of the `BitSlice` borrow.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of the slice, used to mask the live bits.
+- `T: BitStore`: The type of the element.
+**/
+#[derive(Debug)]
+pub struct PartialElementMut<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	inner: PartialElement<'a, O, T>,
+}
+
+impl<'a, O, T> PartialElementMut<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The index of the first live bit in the element.
+	pub fn head(&self) -> BitIdx<T> {
+		self.inner.head()
+	}
+
+	/// The index of the first dead bit after the live bits in the element.
+	pub fn tail(&self) -> BitTail<T> {
+		self.inner.tail()
+	}
+
+	/// Produces a mask of the live bits in the element.
+	///
+	/// # Returns
+	///
+	/// An element with the bits that the slice occupies set, and all others
+	/// cleared.
+	pub fn mask(&self) -> T {
+		self.inner.mask()
+	}
+
+	/// Reads the live bits of the element.
+	///
+	/// # Returns
+	///
+	/// The value of the element, with the bits that the slice does not occupy
+	/// cleared.
+	pub fn load(&self) -> T {
+		self.inner.load()
+	}
+
+	/// Writes the live bits of the element.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: A value whose bits under [`mask`] are written into the
+	///   element. Its other bits are ignored, and the bits of the element that
+	///   the slice does not occupy are unchanged.
+	///
+	/// [`mask`]: #method.mask
+	pub fn store(&mut self, value: T) {
+		let mask = self.mask();
+		self.inner.elem.clear_bits(value | !mask);
+		self.inner.elem.set_bits(value & mask);
+	}
+}
+
 /** Alias for the region components produced by the `BitDomain::splat` method.
 
 Splatting a domain will produce either a `(head, element, tail)` tuple when the
@@ -262,7 +609,7 @@ where T: 'a + BitStore
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::indices::Indexable;
+	use crate::prelude::*;
 
 	#[test]
 	fn minor() {
@@ -318,4 +665,70 @@ mod tests {
 
 		assert!(bp.domain().is_spanning());
 	}
+
+	#[test]
+	fn views() {
+		let data = [0xA5u8, 0x0F, 0xF0, 0x3C];
+		let bits = data.bits::<Msb0>();
+		match bits[2 .. 6].domain() {
+			Domain::Enclave(elem) => {
+				assert_eq!((*elem.head(), *elem.tail()), (2, 6));
+				assert_eq!(elem.mask(), 0x3C);
+				assert_eq!(elem.load(), 0x24);
+			},
+			_ => unreachable!(),
+		}
+		match bits[4 .. 28].domain() {
+			Domain::Region {
+				head: Some(head),
+				body,
+				tail: Some(tail),
+			} => {
+				assert_eq!(head.load(), 0x05);
+				assert_eq!(body, &[0x0F, 0xF0]);
+				assert_eq!(tail.load(), 0x30);
+			},
+			_ => unreachable!(),
+		}
+		match bits[8 ..].domain() {
+			Domain::Region {
+				head: None,
+				body,
+				tail: None,
+			} => assert_eq!(body, &[0x0F, 0xF0, 0x3C]),
+			_ => unreachable!(),
+		}
+		match BitSlice::<Lsb0, u8>::empty().domain() {
+			Domain::Region {
+				head: None,
+				body,
+				tail: None,
+			} => assert!(body.is_empty()),
+			_ => unreachable!(),
+		}
+	}
+
+	#[test]
+	fn views_mut() {
+		let mut data = [0u16; 3];
+		let bits = data.bits_mut::<Lsb0>();
+		let (left, right) = bits.split_at_mut(12);
+		match right[.. 24].domain_mut() {
+			DomainMut::Region {
+				head: Some(mut head),
+				body,
+				tail: Some(mut tail),
+			} => {
+				head.store(!0);
+				body[0] = 0x1234;
+				tail.store(0x00FF);
+			},
+			_ => unreachable!(),
+		}
+		match left[4 .. 8].domain_mut() {
+			DomainMut::Enclave(mut elem) => elem.store(0x00F0),
+			_ => unreachable!(),
+		}
+		assert_eq!(data, [0xF0F0, 0x1234, 0x000F]);
+	}
 }
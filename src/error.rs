@@ -0,0 +1,88 @@
+/*! Errors produced by the fallible API
+
+Most of the library panics when an operation would violate an invariant: an
+index beyond the end of a slice, a vector grown past the number of bits that a
+`BitSlice` handle can address, or a `BitField` transfer between a region and an
+integer of a different width. Each of these operations also has a `try_`
+counterpart, which checks the same conditions and reports a violation as a
+[`BitError`] rather than beginning to unwind.
+
+The `try_` methods that grow a `BitVec` also report when the allocator cannot
+provide the memory they need, where their counterparts would abort the process.
+
+[`BitError`]: enum.BitError.html
+!*/
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+/** The invariant violations that the `try_` methods report.
+
+Each variant carries the values that the panicking counterpart would have
+printed.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitError {
+	/// An index was outside the domain that the operation accepts.
+	OutOfBounds {
+		/// The rejected index.
+		index: usize,
+		/// The length of the slice or vector that the index was checked
+		/// against.
+		len: usize,
+	},
+	/// A vector would grow past the maximum length of a `BitSlice`.
+	TooLong {
+		/// The length that the vector would have had. This saturates at
+		/// `usize::MAX` if the length could not be computed.
+		len: usize,
+		/// The maximum number of bits that a `BitSlice` can hold with the
+		/// vector’s storage type.
+		max: usize,
+	},
+	/// A `BitField` transfer was attempted on an empty region, or on a region
+	/// wider than the integer being transferred.
+	FieldWidth {
+		/// The length of the region.
+		len: usize,
+		/// The width, in bits, of the integer being transferred.
+		width: usize,
+	},
+	/// The allocator could not provide a buffer for a growing vector.
+	AllocFailed {
+		/// The size, in bytes, of the requested buffer.
+		size: usize,
+		/// The alignment, in bytes, of the requested buffer.
+		align: usize,
+	},
+}
+
+impl Display for BitError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			BitError::OutOfBounds { index, len } => {
+				write!(fmt, "Index {} out of bounds: {}", index, len)
+			},
+			BitError::TooLong { len, max } => {
+				write!(fmt, "Capacity overflow: {} exceeds {}", len, max)
+			},
+			BitError::FieldWidth { len, width } => write!(
+				fmt,
+				"Cannot transfer {} bits through a {}-bit region",
+				width, len,
+			),
+			BitError::AllocFailed { size, align } => write!(
+				fmt,
+				"Allocation failed: {} bytes aligned to {}",
+				size, align,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitError {
+}
@@ -0,0 +1,566 @@
+/*! Run-length compressed bit sequences
+
+This module provides [`Ewah`], a bit sequence compressed with the Enhanced
+Word-Aligned Hybrid scheme. The sequence is divided into 64-bit words, and
+stretches of words whose bits are all `0` or all `1` (“clean” words) are
+replaced by a count, while the remaining (“dirty”) words are stored verbatim.
+
+The compressed stream is a series of `u64` words. Each group begins with a
+marker word, laid out from least to most significant bit as:
+
+- bit 0: the value of the bits in the clean run;
+- bits 1 through 32: the number of clean words in the run;
+- bits 33 through 63: the number of dirty words that follow the marker.
+
+Each marker is followed by its dirty words. Within every word, bit `n` holds
+the bit at index `64 * word + n` of the sequence, and bits past the end of the
+sequence are always `0`.
+
+Because the boundaries of the groups always fall on word boundaries, two
+compressed sequences can be combined by walking their groups side by side: a
+clean run in either operand is handled in one step, no matter how long it is,
+and only dirty words opposite dirty words are combined a word at a time.
+
+[`Ewah`]: struct.Ewah.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	fields::BitField,
+	indices::Indexable,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	cmp,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+	},
+	slice,
+};
+
+/// The number of bits in each word of the stream.
+const WORD: usize = 64;
+
+/// The greatest clean-run length that one marker can record.
+const RUN_MAX: u64 = (1 << 32) - 1;
+
+/// The greatest dirty-word count that one marker can record.
+const DIRTY_MAX: u64 = (1 << 31) - 1;
+
+/** A bit sequence compressed with the EWAH run-length scheme.
+
+See the [module documentation] for the stream format.
+
+Logical operations between two `Ewah` sequences run directly on the compressed
+streams. If the operands have different lengths, the shorter is treated as
+though it were extended with `0` bits.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::ewah::Ewah;
+
+let mut bv = bitvec![Msb0, u8; 0; 1_000_000];
+bv[1000 .. 2000].set_all(true);
+bv.set(500_000, true);
+
+let packed = Ewah::encode(&bv);
+assert_eq!(packed.len(), 1_000_000);
+assert_eq!(packed.count_ones(), 1001);
+assert!(packed.as_words().len() < 10);
+assert_eq!(packed.decode::<Msb0, u8>(), bv);
+
+let mask = Ewah::encode(&bitvec![Msb0, u8; 1; 1500]);
+assert_eq!((&packed & &mask).count_ones(), 500);
+```
+
+[module documentation]: index.html
+**/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Ewah {
+	/// The compressed stream.
+	words: Vec<u64>,
+	/// The number of bits in the uncompressed sequence.
+	len: usize,
+}
+
+impl Ewah {
+	/// Compresses a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The bit slice to compress.
+	///
+	/// # Returns
+	///
+	/// The compressed form of `bits`.
+	pub fn encode<O, T>(bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let layout = Layout::of::<O, T>();
+		let mut out = Encoder::default();
+		for chunk in bits.chunks(WORD) {
+			out.push_word(layout.load(chunk));
+		}
+		out.finish(bits.len())
+	}
+
+	/// Decompresses into a bit vector.
+	///
+	/// # Type Parameters
+	///
+	/// - `O`: The ordering of the produced bit vector.
+	/// - `T`: The storage type of the produced bit vector.
+	///
+	/// # Returns
+	///
+	/// A bit vector with the same contents as the sequence that was encoded.
+	pub fn decode<O, T>(&self) -> BitVec<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let layout = Layout::of::<O, T>();
+		let mut out = BitVec::repeat(false, self.len);
+		let mut start = 0;
+		let mut cursor = Cursor::new(&self.words);
+		while let Some(piece) = cursor.peek() {
+			match piece {
+				Piece::Run(true, n) => {
+					let end = cmp::min((start + n) * WORD, self.len);
+					out[start * WORD .. end].set_all(true);
+				},
+				Piece::Run(false, _) => {},
+				Piece::Dirty(word) => {
+					let end = cmp::min((start + 1) * WORD, self.len);
+					layout.store(&mut out[start * WORD .. end], word);
+				},
+			}
+			start += piece.width();
+			cursor.consume(piece.width());
+		}
+		out
+	}
+
+	/// Counts the bits in the uncompressed sequence.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the uncompressed sequence is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Counts the `1` bits in the sequence, without decompressing it.
+	pub fn count_ones(&self) -> usize {
+		let mut cursor = Cursor::new(&self.words);
+		let mut ones = 0;
+		while let Some(piece) = cursor.peek() {
+			ones += match piece {
+				Piece::Run(bit, n) => bit as usize * n * WORD,
+				Piece::Dirty(word) => word.count_ones() as usize,
+			};
+			cursor.consume(piece.width());
+		}
+		ones
+	}
+
+	/// Accesses the compressed stream.
+	///
+	/// # Returns
+	///
+	/// The marker and dirty words of the stream, in the format described in
+	/// the [module documentation].
+	///
+	/// [module documentation]: index.html
+	pub fn as_words(&self) -> &[u64] {
+		&self.words
+	}
+
+	/// Computes the bitwise AND of two sequences.
+	pub fn and(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a & b)
+	}
+
+	/// Computes the bitwise OR of two sequences.
+	pub fn or(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a | b)
+	}
+
+	/// Computes the bitwise XOR of two sequences.
+	pub fn xor(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a ^ b)
+	}
+
+	/// Applies a bitwise operation to two compressed streams.
+	///
+	/// At each step, the next piece of each stream is taken. Two clean runs
+	/// produce a clean run as long as the shorter of them; any other pairing
+	/// produces a single word. An exhausted stream supplies an endless run of
+	/// `0` words.
+	fn combine<F>(&self, other: &Self, op: F) -> Self
+	where F: Fn(u64, u64) -> u64 {
+		let endless = Piece::Run(false, !0);
+		let mut out = Encoder::default();
+		let mut a = Cursor::new(&self.words);
+		let mut b = Cursor::new(&other.words);
+		loop {
+			let (p, q) = match (a.peek(), b.peek()) {
+				(None, None) => break,
+				(p, q) => (p.unwrap_or(endless), q.unwrap_or(endless)),
+			};
+			let width = cmp::min(p.width(), q.width());
+			match (p, q) {
+				(Piece::Run(..), Piece::Run(..)) => {
+					out.push_run(op(p.word(), q.word()) != 0, width)
+				},
+				_ => out.push_word(op(p.word(), q.word())),
+			}
+			a.consume(width);
+			b.consume(width);
+		}
+		out.finish(cmp::max(self.len, other.len))
+	}
+}
+
+impl<O, T> From<&BitSlice<O, T>> for Ewah
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(src: &BitSlice<O, T>) -> Self {
+		Self::encode(src)
+	}
+}
+
+impl BitAnd for &Ewah {
+	type Output = Ewah;
+
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.and(rhs)
+	}
+}
+
+impl BitOr for &Ewah {
+	type Output = Ewah;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.or(rhs)
+	}
+}
+
+impl BitXor for &Ewah {
+	type Output = Ewah;
+
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		self.xor(rhs)
+	}
+}
+
+/** How the indices of a bit slice map onto the bits of its elements.
+
+A stream word holds index `n` of its chunk in bit `n`. `BitField` transfers a
+chunk in order of the significance of its bits in memory, which is the same
+order only when each index of an element is at the position of the same number
+(as in `Lsb0`), or of its mirror (as in `Msb0`, after reversing the word). The
+words of those layouts are moved whole; any other ordering is moved a bit at a
+time.
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Layout {
+	/// Each index `n` is at the position `n`.
+	Ascending,
+	/// Each index `n` is at the position `T::MASK - n`.
+	Descending,
+	/// Any other ordering.
+	Scattered,
+}
+
+impl Layout {
+	/// Finds the layout of an ordering over an element type.
+	fn of<O, T>() -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let at = |n: u8| *O::at::<T>(n.idx());
+		if (0 .. T::BITS).all(|n| at(n) == n) {
+			Layout::Ascending
+		}
+		else if (0 .. T::BITS).all(|n| at(n) == T::MASK - n) {
+			Layout::Descending
+		}
+		else {
+			Layout::Scattered
+		}
+	}
+
+	/// Reads a chunk of at most `WORD` bits into a stream word.
+	fn load<O, T>(self, chunk: &BitSlice<O, T>) -> u64
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		match self {
+			Layout::Ascending => chunk.load_le(),
+			Layout::Descending => {
+				reverse(chunk.load_be()) >> (WORD - chunk.len())
+			},
+			Layout::Scattered => {
+				chunk.iter_ones().fold(0, |word, n| word | 1 << n)
+			},
+		}
+	}
+
+	/// Writes a stream word into a chunk of at most `WORD` bits.
+	fn store<O, T>(self, chunk: &mut BitSlice<O, T>, word: u64)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		match self {
+			Layout::Ascending => chunk.store_le(word),
+			Layout::Descending => {
+				let len = chunk.len();
+				chunk.store_be(reverse(word) >> (WORD - len));
+			},
+			Layout::Scattered => {
+				for n in 0 .. chunk.len() {
+					chunk.set(n, word >> n & 1 != 0);
+				}
+			},
+		}
+	}
+}
+
+/// Reverses the order of the bits in a word.
+fn reverse(word: u64) -> u64 {
+	let word = (word >> 1 & 0x5555_5555_5555_5555)
+		| (word & 0x5555_5555_5555_5555) << 1;
+	let word = (word >> 2 & 0x3333_3333_3333_3333)
+		| (word & 0x3333_3333_3333_3333) << 2;
+	let word = (word >> 4 & 0x0F0F_0F0F_0F0F_0F0F)
+		| (word & 0x0F0F_0F0F_0F0F_0F0F) << 4;
+	word.swap_bytes()
+}
+
+/// One step of a compressed stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Piece {
+	/// A number of consecutive clean words, all of the given bit.
+	Run(bool, usize),
+	/// A single dirty word.
+	Dirty(u64),
+}
+
+impl Piece {
+	/// Counts the words that the piece spans.
+	fn width(self) -> usize {
+		match self {
+			Piece::Run(_, n) => n,
+			Piece::Dirty(_) => 1,
+		}
+	}
+
+	/// Produces any one of the words that the piece spans.
+	fn word(self) -> u64 {
+		match self {
+			Piece::Run(bit, _) => 0u64.wrapping_sub(bit as u64),
+			Piece::Dirty(word) => word,
+		}
+	}
+}
+
+/// Reads a compressed stream piece by piece.
+struct Cursor<'a> {
+	/// The words of the stream that have not yet been read.
+	words: slice::Iter<'a, u64>,
+	/// The bit and remaining length of the current clean run.
+	run: (bool, usize),
+	/// The number of dirty words remaining in the current group.
+	dirty: usize,
+}
+
+impl<'a> Cursor<'a> {
+	fn new(words: &'a [u64]) -> Self {
+		Self {
+			words: words.iter(),
+			run: (false, 0),
+			dirty: 0,
+		}
+	}
+
+	/// Produces the current piece, reading a new marker if the current group
+	/// is exhausted.
+	fn peek(&mut self) -> Option<Piece> {
+		loop {
+			if self.run.1 > 0 {
+				return Some(Piece::Run(self.run.0, self.run.1));
+			}
+			if self.dirty > 0 {
+				return self.words.as_slice().first().copied().map(Piece::Dirty);
+			}
+			let marker = *self.words.next()?;
+			self.run = (marker & 1 != 0, (marker >> 1 & RUN_MAX) as usize);
+			self.dirty = (marker >> 33) as usize;
+		}
+	}
+
+	/// Consumes `width` words from the current piece. `width` must not exceed
+	/// the width of the piece most recently produced by `peek`.
+	fn consume(&mut self, width: usize) {
+		if self.run.1 > 0 {
+			self.run.1 -= width;
+		}
+		else if self.dirty > 0 {
+			self.dirty -= 1;
+			self.words.next();
+		}
+	}
+}
+
+/// Writes a compressed stream.
+///
+/// Runs are merged into the open group whenever it has no dirty words yet, and
+/// clean words are never written as dirty words, so a given sequence of words
+/// always produces the same stream.
+#[derive(Default)]
+struct Encoder {
+	words: Vec<u64>,
+	/// The index of the marker of the open group.
+	marker: Option<usize>,
+}
+
+impl Encoder {
+	/// Appends `count` clean words of `bit`.
+	fn push_run(&mut self, bit: bool, mut count: usize) {
+		while count > 0 {
+			if let Some(idx) = self.marker {
+				let marker = self.words[idx];
+				let run = marker >> 1 & RUN_MAX;
+				if marker >> 33 == 0 && (run == 0 || marker & 1 == bit as u64) {
+					let take = cmp::min(RUN_MAX - run, count as u64);
+					if take > 0 {
+						self.words[idx] = marker & !(RUN_MAX << 1)
+							| (run + take) << 1 | bit as u64;
+						count -= take as usize;
+						continue;
+					}
+				}
+			}
+			self.open();
+		}
+	}
+
+	/// Appends one word, which is stored as a run if it is clean.
+	fn push_word(&mut self, word: u64) {
+		match word {
+			0 => return self.push_run(false, 1),
+			w if w == !0 => return self.push_run(true, 1),
+			_ => {},
+		}
+		let idx = match self.marker {
+			Some(idx) if self.words[idx] >> 33 < DIRTY_MAX => idx,
+			_ => self.open(),
+		};
+		self.words[idx] += 1 << 33;
+		self.words.push(word);
+	}
+
+	/// Begins a new, empty group.
+	fn open(&mut self) -> usize {
+		let idx = self.words.len();
+		self.words.push(0);
+		self.marker = Some(idx);
+		idx
+	}
+
+	fn finish(self, len: usize) -> Ewah {
+		Ewah {
+			words: self.words,
+			len,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::order::{
+		Lsb0,
+		Msb0,
+		Swizzle,
+	};
+
+	#[test]
+	fn round_trip() {
+		let mut bv = BitVec::<Lsb0, u16>::repeat(false, 1000);
+		assert_eq!(Ewah::encode(&bv).as_words(), &[16 << 1]);
+		bv[64 .. 320].set_all(true);
+		bv.set(400, true);
+		bv.set(999, true);
+		let packed = Ewah::encode(&bv);
+		assert_eq!(packed.as_words(), &[
+			1 << 1,
+			1 | 4 << 1,
+			1 << 1 | 1 << 33,
+			1 << 16,
+			8 << 1 | 1 << 33,
+			1 << 39,
+		]);
+		assert_eq!(packed.count_ones(), 258);
+		assert_eq!(packed.decode::<Lsb0, u16>(), bv);
+		assert_eq!(packed.decode::<Msb0, u8>(), bv);
+		let part = Ewah::encode(&bv[3 .. 997]);
+		assert_eq!(part.decode::<Lsb0, u16>(), bv[3 .. 997]);
+		let msb0 = packed.decode::<Msb0, u8>();
+		assert_eq!(Ewah::encode(&msb0[3 .. 997]), part);
+		let swizzle = packed.decode::<Swizzle, u16>();
+		assert_eq!(swizzle, bv);
+		assert_eq!(Ewah::encode(&swizzle[3 .. 997]), part);
+		assert!(Ewah::encode(&bv[.. 0]).as_words().is_empty());
+	}
+
+	#[test]
+	fn logic() {
+		let mut a = BitVec::<Msb0, u32>::repeat(false, 5000);
+		let mut b = BitVec::<Msb0, u32>::repeat(false, 3000);
+		a[100 .. 2500].set_all(true);
+		a[4000 ..].set_all(true);
+		for n in (0 .. 3000).step_by(7) {
+			b.set(n, true);
+		}
+		b[2000 .. 2100].set_all(true);
+		let (x, y) = (Ewah::encode(&a), Ewah::encode(&b));
+		type Op = fn(&Ewah, &Ewah) -> Ewah;
+		type Model = fn(bool, bool) -> bool;
+		let ops: [(Op, Model); 3] = [
+			(Ewah::and, |p, q| p & q),
+			(Ewah::or, |p, q| p | q),
+			(Ewah::xor, |p, q| p ^ q),
+		];
+		for &(op, bit) in ops.iter() {
+			let expected = (0 .. 5000)
+				.map(|n| bit(a[n], b.get(n).copied().unwrap_or(false)))
+				.collect::<BitVec<Msb0, u32>>();
+			for &(l, r) in [(&x, &y), (&y, &x)].iter() {
+				let out = op(l, r);
+				assert_eq!(out.decode::<Msb0, u32>(), expected);
+				assert_eq!(out, Ewah::encode(&expected));
+			}
+		}
+	}
+}
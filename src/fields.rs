@@ -36,20 +36,65 @@ The provided `BitOrder` implementors `Lsb0` and `Msb0` use the local machine’s
 byte ordering. Other cursors *may* implement ordering of bytes within `T`
 elements differently, for instance by calling `.to_be_bytes` before store and
 `from_be_bytes` after load,
+
+# Wire Formats
+
+When `T` is `u8`, element significance *is* byte significance, and the methods
+of this trait address packed wire formats directly, on any target. The two
+conventions found in practice are:
+
+- Network order, used by IPv4, TCP, and CAN “Motorola” signals: bytes are
+  big-endian, and bits are numbered from the most significant bit of each byte.
+  Use `BitSlice<Msb0, u8>` with the `_be` methods, or with [`Endian::Big`].
+- Intel order, used by CAN “Intel” signals and many little-endian peripherals:
+  bytes are little-endian, and bits are numbered from the least significant bit
+  of each byte. Use `BitSlice<Lsb0, u8>` with the `_le` methods, or with
+  [`Endian::Little`].
+
+In both conventions, a field of any width is a contiguous range of the slice,
+and each byte holds its fragment of the value in the byte’s own bit
+significance.
+
+```rust
+use bitvec::prelude::*;
+
+//  The flags and 13-bit fragment offset of an IPv4 header.
+let mut word = [0u8; 2];
+let bits = word.bits_mut::<Msb0>();
+bits[1 .. 3].store_be(0b10u8);
+bits[3 ..].store_be(0x1234u16);
+assert_eq!(bits[3 ..].load_be::<u16>(), 0x1234);
+assert_eq!(word, [0x52, 0x34]);
+```
+
+[`Endian::Big`]: enum.Endian.html#variant.Big
+[`Endian::Little`]: enum.Endian.html#variant.Little
 !*/
 
 use crate::{
 	access::BitAccess,
+	array::BitArray,
+	error::BitError,
+	indices::Indexable,
 	order::{
+		BitOrder,
 		Lsb0,
 		Msb0,
 	},
-	slice::BitSlice,
-	store::BitStore,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
+	store::{
+		BitStore,
+		Sealed,
+	},
+	volatile::VolatileBitSlice,
 };
 
 use core::{
 	cmp,
+	fmt::Debug,
 	mem,
 	ptr,
 };
@@ -59,7 +104,6 @@ use either::Either;
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
-	order::BitOrder,
 	vec::BitVec,
 };
 
@@ -270,274 +314,1273 @@ pub trait BitField {
 	/// If `self` is empty, or wider than a single `U` element, this panics.
 	fn store_be<U>(&mut self, value: U)
 	where U: BitStore;
+
+	/// Load from `self`, using a runtime-selected element ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A read reference to some bits in memory.
+	/// - `endian`: Selects between [`load_le`] and [`load_be`].
+	///
+	/// # Returns
+	///
+	/// The value loaded by the selected method.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `U` element, this panics.
+	///
+	/// [`load_be`]: #tymethod.load_be
+	/// [`load_le`]: #tymethod.load_le
+	fn load_endian<U>(&self, endian: Endian) -> U
+	where U: BitStore {
+		match endian {
+			Endian::Little => self.load_le(),
+			Endian::Big => self.load_be(),
+		}
+	}
+
+	/// Store into `self`, using a runtime-selected element ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A write reference to some bits in memory.
+	/// - `value`: A value, whose `self.len()` least significant bits will be
+	///   stored into `self`.
+	/// - `endian`: Selects between [`store_le`] and [`store_be`].
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `U` element, this panics.
+	///
+	/// [`store_be`]: #tymethod.store_be
+	/// [`store_le`]: #tymethod.store_le
+	fn store_endian<U>(&mut self, value: U, endian: Endian)
+	where U: BitStore {
+		match endian {
+			Endian::Little => self.store_le(value),
+			Endian::Big => self.store_be(value),
+		}
+	}
+}
+
+/** The significance order of the elements that a multi-element field spans.
+
+This selects between the `_le` and `_be` methods of [`BitField`]. When the
+storage type is `u8`, it is also the byte order of the field; see the [module
+documentation] for the wire formats that this supports.
+
+[`BitField`]: trait.BitField.html
+[module documentation]: index.html#wire-formats
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endian {
+	/// The lowest-addressed element holds the least significant bits.
+	Little,
+	/// The lowest-addressed element holds the most significant bits.
+	Big,
+}
+
+/** Signed loads and stores for `BitField` regions.
+
+This trait extends [`BitField`] with sign-extending loads into, and
+range-checked stores from, the signed integers. It is implemented for every
+`BitSlice`, and so is available on `BitArray`, `BitBox`, and `BitVec` through
+their dereference to `BitSlice`.
+
+[`BitField`]: trait.BitField.html
+**/
+pub trait BitFieldSigned: BitField {
+	/// Load the sequence of bits from `self` into a signed integer,
+	/// sign-extending from the most significant bit of the region.
+	///
+	/// The default implementation of this function calls [`load_le_signed`] on
+	/// little-endian byte-ordered CPUs, and [`load_be_signed`] on big-endian
+	/// byte-ordered CPUs.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A read reference to some bits in memory. This slice must be
+	///   trimmed to have a width no more than the width of the type being
+	///   loaded.
+	///
+	/// # Returns
+	///
+	/// An `I` value whose least `self.len()` significant bits are filled with
+	/// the bits of `self`, and whose remaining bits are all copies of the
+	/// most significant bit of `self`.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `I` element, this panics.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.bits_mut::<Lsb0>();
+	/// bits[2 .. 7].store_signed(-3i8);
+	/// assert_eq!(bits[2 .. 7].load_signed::<i8>(), -3);
+	/// assert_eq!(bits[2 .. 7].load::<u8>(), 0b11101);
+	/// ```
+	///
+	/// [`load_be_signed`]: #tymethod.load_be_signed
+	/// [`load_le_signed`]: #tymethod.load_le_signed
+	fn load_signed<I>(&self) -> I
+	where I: Signed {
+		#[cfg(target_endian = "little")]
+		return self.load_le_signed();
+
+		#[cfg(target_endian = "big")]
+		return self.load_be_signed();
+	}
+
+	/// Load from `self` into a signed integer, using little-endian element
+	/// ordering.
+	///
+	/// This behaves as [`load_le`], then sign-extends the loaded value from the
+	/// most significant bit of the region.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `I` element, this panics.
+	///
+	/// [`load_le`]: trait.BitField.html#tymethod.load_le
+	fn load_le_signed<I>(&self) -> I
+	where I: Signed;
+
+	/// Load from `self` into a signed integer, using big-endian element
+	/// ordering.
+	///
+	/// This behaves as [`load_be`], then sign-extends the loaded value from the
+	/// most significant bit of the region.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `I` element, this panics.
+	///
+	/// [`load_be`]: trait.BitField.html#tymethod.load_be
+	fn load_be_signed<I>(&self) -> I
+	where I: Signed;
+
+	/// Stores a signed integer into the domain of `self`, in two’s-complement.
+	///
+	/// The default implementation of this function calls [`store_le_signed`]
+	/// on little-endian byte-ordered CPUs, and [`store_be_signed`] on
+	/// big-endian byte-ordered CPUs.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A write reference to some bits in memory. This slice must
+	///   be trimmed to have a width no more than the width of the type being
+	///   stored.
+	/// - `value`: A value which must be representable in `self.len()` bits of
+	///   two’s-complement.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `I` element, or if `value`
+	/// does not fit in `self.len()` bits, this panics.
+	///
+	/// [`store_be_signed`]: #tymethod.store_be_signed
+	/// [`store_le_signed`]: #tymethod.store_le_signed
+	fn store_signed<I>(&mut self, value: I)
+	where I: Signed {
+		#[cfg(target_endian = "little")]
+		self.store_le_signed(value);
+
+		#[cfg(target_endian = "big")]
+		self.store_be_signed(value);
+	}
+
+	/// Store a signed integer into `self`, using little-endian element
+	/// ordering.
+	///
+	/// This checks that `value` fits in the region, then behaves as
+	/// [`store_le`].
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `I` element, or if `value`
+	/// does not fit in `self.len()` bits, this panics.
+	///
+	/// [`store_le`]: trait.BitField.html#tymethod.store_le
+	fn store_le_signed<I>(&mut self, value: I)
+	where I: Signed;
+
+	/// Store a signed integer into `self`, using big-endian element ordering.
+	///
+	/// This checks that `value` fits in the region, then behaves as
+	/// [`store_be`].
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than a single `I` element, or if `value`
+	/// does not fit in `self.len()` bits, this panics.
+	///
+	/// [`store_be`]: trait.BitField.html#tymethod.store_be
+	fn store_be_signed<I>(&mut self, value: I)
+	where I: Signed;
+}
+
+impl<O, T> BitFieldSigned for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn load_le_signed<I>(&self) -> I
+	where I: Signed {
+		I::from_unsigned(sign_extend(self.load_le(), self.len()))
+	}
+
+	fn load_be_signed<I>(&self) -> I
+	where I: Signed {
+		I::from_unsigned(sign_extend(self.load_be(), self.len()))
+	}
+
+	fn store_le_signed<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_le(check_signed(value, self.len()));
+	}
+
+	fn store_be_signed<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_be(check_signed(value, self.len()));
+	}
+}
+
+/** Signed integers that `BitField` can load and store.
+
+Each signed integer is transferred through the unsigned `BitStore` type of the
+same width, and converted with `as`.
+**/
+pub trait Signed: Sealed + Copy + Debug {
+	/// The unsigned type of the same width.
+	type Unsigned: BitStore;
+
+	/// Reinterprets an unsigned value as signed.
+	fn from_unsigned(value: Self::Unsigned) -> Self;
+
+	/// Reinterprets a signed value as unsigned.
+	fn to_unsigned(self) -> Self::Unsigned;
+}
+
+macro_rules! signed {
+	($($i:ty => $u:ty),* $(,)?) => { $(
+		impl Signed for $i {
+			type Unsigned = $u;
+
+			#[inline(always)]
+			fn from_unsigned(value: $u) -> Self {
+				value as Self
+			}
+
+			#[inline(always)]
+			fn to_unsigned(self) -> $u {
+				self as $u
+			}
+		}
+	)* };
+}
+
+signed! {
+	i8 => u8,
+	i16 => u16,
+	i32 => u32,
+	isize => usize,
+}
+
+#[cfg(target_pointer_width = "64")]
+signed!(i64 => u64);
+
+/** Loads and stores for fields wider than one `BitStore` value.
+
+`BitField` transfers at most one `U: BitStore` value, which is never wider than
+`usize`. This trait transfers fields of any width, into and out of `u128` or a
+slice of `U` words, with the same element significance conventions as the
+`_le` and `_be` methods of [`BitField`]. It is implemented for every
+`BitSlice`, and so is available on `BitArray`, `BitBox`, and `BitVec` through
+their dereference to `BitSlice`.
+
+The word slices are ordered to match the element significance: the `_le`
+methods put the least significant word first, and the `_be` methods put the
+most significant word first. When the field is a whole number of elements of
+the same type as the words, both therefore copy the elements in address order.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut data = [0u8; 20];
+let bits = data.bits_mut::<Msb0>();
+bits[4 .. 132].store_be_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+assert_eq!(data[0], 0x00);
+assert_eq!(data[1], 0x12);
+assert_eq!(data[16], 0x00);
+
+let mut words = [0u32; 4];
+data.bits::<Msb0>()[4 .. 132].load_be_words(&mut words);
+assert_eq!(words, [0x0123_4567, 0x89AB_CDEF, 0xFEDC_BA98, 0x7654_3210]);
+```
+
+[`BitField`]: trait.BitField.html
+**/
+pub trait BitFieldWide: BitField {
+	/// Load the sequence of bits from `self` into a `u128`.
+	///
+	/// The default implementation of this function calls [`load_le_u128`] on
+	/// little-endian byte-ordered CPUs, and [`load_be_u128`] on big-endian
+	/// byte-ordered CPUs.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than 128 bits, this panics.
+	///
+	/// [`load_be_u128`]: #tymethod.load_be_u128
+	/// [`load_le_u128`]: #tymethod.load_le_u128
+	fn load_u128(&self) -> u128 {
+		#[cfg(target_endian = "little")]
+		return self.load_le_u128();
+
+		#[cfg(target_endian = "big")]
+		return self.load_be_u128();
+	}
+
+	/// Load from `self` into a `u128`, using little-endian element ordering.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than 128 bits, this panics.
+	fn load_le_u128(&self) -> u128;
+
+	/// Load from `self` into a `u128`, using big-endian element ordering.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than 128 bits, this panics.
+	fn load_be_u128(&self) -> u128;
+
+	/// Store the least significant `self.len()` bits of a `u128` into `self`.
+	///
+	/// The default implementation of this function calls [`store_le_u128`] on
+	/// little-endian byte-ordered CPUs, and [`store_be_u128`] on big-endian
+	/// byte-ordered CPUs.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than 128 bits, this panics.
+	///
+	/// [`store_be_u128`]: #tymethod.store_be_u128
+	/// [`store_le_u128`]: #tymethod.store_le_u128
+	fn store_u128(&mut self, value: u128) {
+		#[cfg(target_endian = "little")]
+		self.store_le_u128(value);
+
+		#[cfg(target_endian = "big")]
+		self.store_be_u128(value);
+	}
+
+	/// Store a `u128` into `self`, using little-endian element ordering.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than 128 bits, this panics.
+	fn store_le_u128(&mut self, value: u128);
+
+	/// Store a `u128` into `self`, using big-endian element ordering.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than 128 bits, this panics.
+	fn store_be_u128(&mut self, value: u128);
+
+	/// Load from `self` into a slice of words, using little-endian element
+	/// ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A read reference to some bits in memory, no wider than all of
+	///   `words` together.
+	/// - `words`: The destination. `words[0]` receives the least significant
+	///   bits of the field; bits of `words` above the field are cleared.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than `words`, this panics.
+	fn load_le_words<U>(&self, words: &mut [U])
+	where U: BitStore;
+
+	/// Load from `self` into a slice of words, using big-endian element
+	/// ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: A read reference to some bits in memory, no wider than all of
+	///   `words` together.
+	/// - `words`: The destination. The last word receives the least significant
+	///   bits of the field; bits of `words` above the field are cleared.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than `words`, this panics.
+	fn load_be_words<U>(&self, words: &mut [U])
+	where U: BitStore;
+
+	/// Store a slice of words into `self`, using little-endian element
+	/// ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A write reference to some bits in memory, no wider than
+	///   all of `words` together.
+	/// - `words`: The source, least significant word first. Its
+	///   `self.len()` least significant bits are stored.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than `words`, this panics.
+	fn store_le_words<U>(&mut self, words: &[U])
+	where U: BitStore;
+
+	/// Store a slice of words into `self`, using big-endian element ordering.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A write reference to some bits in memory, no wider than
+	///   all of `words` together.
+	/// - `words`: The source, most significant word first. Its `self.len()`
+	///   least significant bits are stored.
+	///
+	/// # Panics
+	///
+	/// If `self` is empty, or wider than `words`, this panics.
+	fn store_be_words<U>(&mut self, words: &[U])
+	where U: BitStore;
+}
+
+impl<O, T> BitFieldWide for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn load_le_u128(&self) -> u128 {
+		let mut words = [0u32; 4];
+		gather(self, false, &mut words, false);
+		join_u128(words)
+	}
+
+	fn load_be_u128(&self) -> u128 {
+		let mut words = [0u32; 4];
+		gather(self, true, &mut words, false);
+		join_u128(words)
+	}
+
+	fn store_le_u128(&mut self, value: u128) {
+		scatter(self, false, &split_u128(value), false);
+	}
+
+	fn store_be_u128(&mut self, value: u128) {
+		scatter(self, true, &split_u128(value), false);
+	}
+
+	fn load_le_words<U>(&self, words: &mut [U])
+	where U: BitStore {
+		gather(self, false, words, false);
+	}
+
+	fn load_be_words<U>(&self, words: &mut [U])
+	where U: BitStore {
+		gather(self, true, words, true);
+	}
+
+	fn store_le_words<U>(&mut self, words: &[U])
+	where U: BitStore {
+		scatter(self, false, words, false);
+	}
+
+	fn store_be_words<U>(&mut self, words: &[U])
+	where U: BitStore {
+		scatter(self, true, words, true);
+	}
+}
+
+/** Field access for every ordering.
+
+Each method checks the width of the slice, and then hands the access to the
+slice’s ordering, through the hidden field methods on `BitOrder`.
+
+`Lsb0` and `Msb0` keep the live bits of each element in a single run, at a
+distance from the LSedge that is known from the slice’s head and tail indices.
+They move each chunk of the value with one shift and mask, in the functions
+named `*_lsb0` and `*_msb0` below.
+
+Every other ordering uses the functions named `*_any` below. There, each
+element under the slice contributes one chunk of the value: the bits of the
+element that are live in the slice, taken in order of their significance in the
+element and packed against the least significant edge. This is a parallel bit
+extract (on load) or deposit (on store) with the mask produced by
+`BitOrder::mask_range`. When that mask is contiguous, each chunk is still moved
+with a single shift and mask; orderings which scatter adjacent indices across an
+element fall back to moving each live bit of the partial edge elements
+individually. Fully-live elements are always moved whole.
+**/
+impl<O, T> BitField for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn load_le<U>(&self) -> U
+	where U: BitStore {
+		let len = self.len();
+		if !(1 ..= U::BITS as usize).contains(&len) {
+			panic!("Cannot load {} bits from a {}-bit region", U::BITS, len);
+		}
+		O::field_load_le(self)
+	}
+
+	fn load_be<U>(&self) -> U
+	where U: BitStore {
+		let len = self.len();
+		if !(1 ..= U::BITS as usize).contains(&len) {
+			panic!("Cannot load {} bits from a {}-bit region", U::BITS, len);
+		}
+		O::field_load_be(self)
+	}
+
+	fn store_le<U>(&mut self, value: U)
+	where U: BitStore {
+		let len = self.len();
+		if !(1 ..= U::BITS as usize).contains(&len) {
+			panic!("Cannot store {} bits in a {}-bit region", U::BITS, len);
+		}
+		O::field_store_le(self, value & mask_for(len));
+	}
+
+	fn store_be<U>(&mut self, value: U)
+	where U: BitStore {
+		let len = self.len();
+		if !(1 ..= U::BITS as usize).contains(&len) {
+			panic!("Cannot store {} bits in a {}-bit region", U::BITS, len);
+		}
+		O::field_store_be(self, value & mask_for(len));
+	}
+}
+
+/** Loads a little-endian field from a slice of any ordering.
+
+This is the provided implementation of `BitOrder::field_load_le`. The callers
+of all the field functions below have already checked that `bits` is not empty
+and not wider than `U`, and the store functions receive a `value` with no bits
+set above `bits.len()`.
+**/
+pub(crate) fn load_le_any<O, T, U>(bits: &BitSlice<O, T>) -> U
+where
+	O: BitOrder,
+	T: BitStore,
+	U: BitStore,
+{
+	//  Read the chunks from most significant (high address) to least
+	//  significant (low address), pushing each into the accumulator from the
+	//  LSedge.
+	let (head, body, tail) = live_elements(bits);
+	let mut accum = 0u128;
+	if let Some((elt, mask)) = tail {
+		push_chunk(&mut accum, elt.load(), mask);
+	}
+	for elt in body.iter().rev() {
+		push_chunk(&mut accum, elt.load(), T::TRUE);
+	}
+	if let Some((elt, mask)) = head {
+		push_chunk(&mut accum, elt.load(), mask);
+	}
+	resize(accum)
+}
+
+/// Loads a big-endian field from a slice of any ordering.
+pub(crate) fn load_be_any<O, T, U>(bits: &BitSlice<O, T>) -> U
+where
+	O: BitOrder,
+	T: BitStore,
+	U: BitStore,
+{
+	//  Read the chunks from most significant (low address) to least
+	//  significant (high address).
+	let (head, body, tail) = live_elements(bits);
+	let mut accum = 0u128;
+	if let Some((elt, mask)) = head {
+		push_chunk(&mut accum, elt.load(), mask);
+	}
+	for elt in body.iter() {
+		push_chunk(&mut accum, elt.load(), T::TRUE);
+	}
+	if let Some((elt, mask)) = tail {
+		push_chunk(&mut accum, elt.load(), mask);
+	}
+	resize(accum)
+}
+
+/// Stores a little-endian field into a slice of any ordering.
+pub(crate) fn store_le_any<O, T, U>(bits: &mut BitSlice<O, T>, value: U)
+where
+	O: BitOrder,
+	T: BitStore,
+	U: BitStore,
+{
+	//  Write the chunks from least significant (low address) to most
+	//  significant (high address), taking each from the value’s LSedge.
+	let mut value: u128 = resize(value);
+	let (head, body, tail) = live_elements(bits);
+	if let Some((elt, mask)) = head {
+		pop_chunk(&mut value, elt, mask);
+	}
+	for elt in body.iter() {
+		pop_chunk(&mut value, elt, T::TRUE);
+	}
+	if let Some((elt, mask)) = tail {
+		pop_chunk(&mut value, elt, mask);
+	}
+}
+
+/// Stores a big-endian field into a slice of any ordering.
+pub(crate) fn store_be_any<O, T, U>(bits: &mut BitSlice<O, T>, value: U)
+where
+	O: BitOrder,
+	T: BitStore,
+	U: BitStore,
+{
+	//  Write the chunks from least significant (high address) to most
+	//  significant (low address).
+	let mut value: u128 = resize(value);
+	let (head, body, tail) = live_elements(bits);
+	if let Some((elt, mask)) = tail {
+		pop_chunk(&mut value, elt, mask);
+	}
+	for elt in body.iter().rev() {
+		pop_chunk(&mut value, elt, T::TRUE);
+	}
+	if let Some((elt, mask)) = head {
+		pop_chunk(&mut value, elt, mask);
+	}
+}
+
+/** Loads a little-endian field from an `Lsb0` slice.
+
+In `Lsb0`, the head index of a slice is also the distance of its live region
+from the LSedge of the first element, and the tail index is the width of the
+live region in the last element, starting at the LSedge.
+**/
+pub(crate) fn load_le_lsb0<T, U>(bits: &BitSlice<Lsb0, T>) -> U
+where
+	T: BitStore,
+	U: BitStore,
+{
+	match bits.bitptr().domain().splat() {
+		/* The live bits are in the interior of a single element.
+
+		This path only needs to load the element, shift it right by the distance
+		from LSedge to the live region, and mask it for the length of `bits`.
+		*/
+		Either::Right((head, elt, _)) => {
+			resize((elt.load() >> *head) & mask_for::<T>(bits.len()))
+		},
+		/* The live region touches at least one element edge.
+
+		This block reads chunks from the slice memory into an accumulator, from
+		the most-significant chunk to the least-significant. Each read must
+		collect the live section of the chunk into a temporary, then shift the
+		accumulator left by the chunk’s bit width, then write the temporary
+		into the newly-vacated least significant bits of the accumulator.
+		*/
+		Either::Left((head, body, tail)) => {
+			let mut accum = 0u128;
+
+			//  If the tail exists, it contains the most significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((tail, t)) = tail {
+				//  Load, mask, resize, and store. No other data is present.
+				accum = resize(tail.load() & mask_for::<T>(*t as usize));
+			}
+			//  Read the body elements, from high address to low, into the
+			//  accumulator.
+			for elt in body.unwrap_or(&[]).iter().rev() {
+				shift_in(&mut accum, resize(elt.load()), T::BITS);
+			}
+			//  If the head exists, it contains the least significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((h, head)) = head {
+				//  Get the live region’s distance from the LSedge.
+				let lsedge = *h;
+				//  Load the element, shift down to LSedge, and resize.
+				let val = resize(head.load() >> lsedge);
+				shift_in(&mut accum, val, T::BITS - lsedge);
+			}
+
+			resize(accum)
+		},
+	}
+}
+
+/// Loads a big-endian field from an `Lsb0` slice.
+pub(crate) fn load_be_lsb0<T, U>(bits: &BitSlice<Lsb0, T>) -> U
+where
+	T: BitStore,
+	U: BitStore,
+{
+	match bits.bitptr().domain().splat() {
+		Either::Right((head, elt, _)) => {
+			resize((elt.load() >> *head) & mask_for::<T>(bits.len()))
+		},
+		Either::Left((head, body, tail)) => {
+			let mut accum = 0u128;
+
+			//  If the head exists, it contains the most significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((h, head)) = head {
+				//  Load, move, resize, and store. No other data is present.
+				accum = resize(head.load() >> *h);
+			}
+			//  Read the body elements, from low address to high, into the
+			//  accumulator.
+			for elt in body.unwrap_or(&[]).iter() {
+				shift_in(&mut accum, resize(elt.load()), T::BITS);
+			}
+			//  If the tail exists, it contains the least significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((tail, t)) = tail {
+				//  Load, mask, and resize.
+				let val = resize(tail.load() & mask_for::<T>(*t as usize));
+				shift_in(&mut accum, val, *t);
+			}
+
+			resize(accum)
+		},
+	}
+}
+
+/// Stores a little-endian field into an `Lsb0` slice.
+pub(crate) fn store_le_lsb0<T, U>(bits: &mut BitSlice<Lsb0, T>, value: U)
+where
+	T: BitStore,
+	U: BitStore,
+{
+	let len = bits.len();
+	match bits.bitptr().domain().splat() {
+		/* The live region is in the interior of a single element.
+
+		The `value` is shifted left by the region’s distance from the LSedge,
+		then written directly into place.
+		*/
+		Either::Right((head, elt, _)) => {
+			//  Get the region’s distance from the LSedge.
+			let lsedge = *head;
+			//  Erase the live region.
+			elt.clear_bits(!(mask_for::<T>(len) << lsedge));
+			//  Shift the value to fit the region, and write.
+			elt.set_bits(resize::<U, T>(value) << lsedge);
+		},
+		/* The live region touches at least one element edge.
+
+		This block writes chunks from the value into slice memory, from the
+		least-significant chunk to the most-significant. Each write moves a
+		slice chunk’s width of bits from the LSedge of the value into memory,
+		then shifts the value right by that width.
+		*/
+		Either::Left((head, body, tail)) => {
+			let mut value: u128 = resize(value);
+
+			//  If the head exists, it contains the least significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((h, head)) = head {
+				//  Get the region distance from the LSedge.
+				let lsedge = *h;
+				//  Find the region width (MSedge to head).
+				let width = T::BITS - lsedge;
+				//  Erase the region.
+				head.clear_bits(T::TRUE >> width);
+				//  Shift the value’s LSedge bits to fit the region, and write.
+				head.set_bits(resize::<u128, T>(value) << lsedge);
+				//  Discard the now-written bits from the value.
+				shift_out(&mut value, width);
+			}
+			//  Write into the body elements, from low address to high, from the
+			//  value.
+			for elt in body.unwrap_or(&[]).iter() {
+				elt.store(resize(value));
+				shift_out(&mut value, T::BITS);
+			}
+			//  If the tail exists, it contains the most significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((tail, t)) = tail {
+				//  Get the region width.
+				let width = *t;
+				//  Erase the region.
+				tail.clear_bits(T::TRUE << width);
+				//  Write the value’s region-width LSedge bits into the region.
+				let val = resize::<u128, T>(value) & mask_for(width as usize);
+				tail.set_bits(val);
+			}
+		},
+	}
+}
+
+/// Stores a big-endian field into an `Lsb0` slice.
+pub(crate) fn store_be_lsb0<T, U>(bits: &mut BitSlice<Lsb0, T>, value: U)
+where
+	T: BitStore,
+	U: BitStore,
+{
+	let len = bits.len();
+	match bits.bitptr().domain().splat() {
+		Either::Right((head, elt, _)) => {
+			let lsedge = *head;
+			elt.clear_bits(!(mask_for::<T>(len) << lsedge));
+			elt.set_bits(resize::<U, T>(value) << lsedge);
+		},
+		Either::Left((head, body, tail)) => {
+			let mut value: u128 = resize(value);
+
+			//  If the tail exists, it contains the least significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((tail, t)) = tail {
+				//  Get the region width.
+				let width = *t;
+				//  Erase the region.
+				tail.clear_bits(T::TRUE << width);
+				//  Write the value’s region-width LSedge bits into the region.
+				let val = resize::<u128, T>(value) & mask_for(width as usize);
+				tail.set_bits(val);
+				//  Discard the now-written bits from the value.
+				shift_out(&mut value, width);
+			}
+			//  Write into the body elements, from high address to low, from the
+			//  value.
+			for elt in body.unwrap_or(&[]).iter().rev() {
+				elt.store(resize(value));
+				shift_out(&mut value, T::BITS);
+			}
+			//  If the head exists, it contains the most significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((h, head)) = head {
+				//  Get the region distance from the LSedge.
+				let lsedge = *h;
+				//  Erase the region (MSedge to head).
+				head.clear_bits(T::TRUE >> (T::BITS - lsedge));
+				//  Shift the value’s LSedge bits to fit the region, and write.
+				head.set_bits(resize::<u128, T>(value) << lsedge);
+			}
+		},
+	}
+}
+
+/** Loads a little-endian field from an `Msb0` slice.
+
+In `Msb0`, the live region of the first element runs from the head index down
+to the LSedge, and the live region of the last element runs from the MSedge
+down for the tail index’s width.
+**/
+pub(crate) fn load_le_msb0<T, U>(bits: &BitSlice<Msb0, T>) -> U
+where
+	T: BitStore,
+	U: BitStore,
+{
+	match bits.bitptr().domain().splat() {
+		/* The live bits are in the interior of a single element.
+
+		This path only needs to load the element, shift it right by the distance
+		from LSedge to the live region, and mask it for the length of `bits`.
+		*/
+		Either::Right((_, elt, tail)) => {
+			let lsedge = T::BITS - *tail;
+			resize((elt.load() >> lsedge) & mask_for::<T>(bits.len()))
+		},
+		/* The live region touches at least one element edge.
+
+		This block reads chunks from the slice memory into an accumulator, from
+		the most-significant chunk to the least-significant. Each read must
+		collect the live section of the chunk into a temporary, then shift the
+		accumulator left by the chunk’s bit width, then write the temporary
+		into the newly-vacated least significant bits of the accumulator.
+		*/
+		Either::Left((head, body, tail)) => {
+			let mut accum = 0u128;
+
+			//  If the tail exists, it contains the most significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((tail, t)) = tail {
+				//  Find the live region’s distance from the LSedge.
+				let lsedge = T::BITS - *t;
+				//  Load, move, resize, and store. No other data is present.
+				accum = resize(tail.load() >> lsedge);
+			}
+			//  Read the body elements, from high address to low, into the
+			//  accumulator.
+			for elt in body.unwrap_or(&[]).iter().rev() {
+				shift_in(&mut accum, resize(elt.load()), T::BITS);
+			}
+			//  If the head exists, it contains the least significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((h, head)) = head {
+				//  Find the region width (head to LSedge).
+				let width = T::BITS - *h;
+				//  Load the element, mask, and resize.
+				let val = resize(head.load() & mask_for::<T>(width as usize));
+				shift_in(&mut accum, val, width);
+			}
+
+			resize(accum)
+		},
+	}
+}
+
+/// Loads a big-endian field from an `Msb0` slice.
+pub(crate) fn load_be_msb0<T, U>(bits: &BitSlice<Msb0, T>) -> U
+where
+	T: BitStore,
+	U: BitStore,
+{
+	match bits.bitptr().domain().splat() {
+		Either::Right((_, elt, tail)) => {
+			let lsedge = T::BITS - *tail;
+			resize((elt.load() >> lsedge) & mask_for::<T>(bits.len()))
+		},
+		Either::Left((head, body, tail)) => {
+			let mut accum = 0u128;
+
+			//  If the head exists, it contains the most significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((h, head)) = head {
+				//  Find the region width (head to LSedge).
+				let width = T::BITS - *h;
+				//  Load, mask, resize, and store. No other data is present.
+				accum = resize(head.load() & mask_for::<T>(width as usize));
+			}
+			//  Read the body elements, from low address to high, into the
+			//  accumulator.
+			for elt in body.unwrap_or(&[]).iter() {
+				shift_in(&mut accum, resize(elt.load()), T::BITS);
+			}
+			//  If the tail exists, it contains the least significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((tail, t)) = tail {
+				//  Find the live region’s distance from LSedge.
+				let lsedge = T::BITS - *t;
+				//  Load the element, shift down to LSedge, and resize.
+				shift_in(&mut accum, resize(tail.load() >> lsedge), *t);
+			}
+
+			resize(accum)
+		},
+	}
+}
+
+/// Stores a little-endian field into an `Msb0` slice.
+pub(crate) fn store_le_msb0<T, U>(bits: &mut BitSlice<Msb0, T>, value: U)
+where
+	T: BitStore,
+	U: BitStore,
+{
+	let len = bits.len();
+	match bits.bitptr().domain().splat() {
+		/* The live region is in the interior of a single element.
+
+		The `value` is shifted left by the region’s distance from the LSedge,
+		then written directly into place.
+		*/
+		Either::Right((_, elt, tail)) => {
+			//  Get the region’s distance from the LSedge.
+			let lsedge = T::BITS - *tail;
+			//  Erase the live region.
+			elt.clear_bits(!(mask_for::<T>(len) << lsedge));
+			//  Shift the value to fit the region, and write.
+			elt.set_bits(resize::<U, T>(value) << lsedge);
+		},
+		/* The live region touches at least one element edge.
+
+		This block writes chunks from the value into slice memory, from the
+		least-significant chunk to the most-significant. Each write moves a
+		slice chunk’s width of bits from the LSedge of the value into memory,
+		then shifts the value right by that width.
+		*/
+		Either::Left((head, body, tail)) => {
+			let mut value: u128 = resize(value);
+
+			//  If the head exists, it contains the least significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((h, head)) = head {
+				//  Get the region width (head to LSedge).
+				let width = T::BITS - *h;
+				//  Erase the region.
+				head.clear_bits(T::TRUE << width);
+				//  Write the value’s region-width LSedge bits into the region.
+				let val = resize::<u128, T>(value) & mask_for(width as usize);
+				head.set_bits(val);
+				//  Discard the now-written bits from the value.
+				shift_out(&mut value, width);
+			}
+			//  Write into the body elements, from low address to high, from the
+			//  value.
+			for elt in body.unwrap_or(&[]).iter() {
+				elt.store(resize(value));
+				shift_out(&mut value, T::BITS);
+			}
+			//  If the tail exists, it contains the most significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((tail, t)) = tail {
+				//  Find the region distance from the LSedge.
+				let lsedge = T::BITS - *t;
+				//  Erase the region.
+				tail.clear_bits(T::TRUE >> *t);
+				//  Shift the value’s LSedge bits to fit the region, and write.
+				tail.set_bits(resize::<u128, T>(value) << lsedge);
+			}
+		},
+	}
+}
+
+/// Stores a big-endian field into an `Msb0` slice.
+pub(crate) fn store_be_msb0<T, U>(bits: &mut BitSlice<Msb0, T>, value: U)
+where
+	T: BitStore,
+	U: BitStore,
+{
+	let len = bits.len();
+	match bits.bitptr().domain().splat() {
+		Either::Right((_, elt, tail)) => {
+			let lsedge = T::BITS - *tail;
+			elt.clear_bits(!(mask_for::<T>(len) << lsedge));
+			elt.set_bits(resize::<U, T>(value) << lsedge);
+		},
+		Either::Left((head, body, tail)) => {
+			let mut value: u128 = resize(value);
+
+			//  If the tail exists, it contains the least significant chunk of
+			//  the value, on the MSedge side.
+			if let Some((tail, t)) = tail {
+				//  Get the region width (MSedge to tail).
+				let width = *t;
+				//  Find the region distance from the LSedge.
+				let lsedge = T::BITS - width;
+				//  Erase the region.
+				tail.clear_bits(T::TRUE >> width);
+				//  Shift the value’s LSedge bits to fit the region, and write.
+				tail.set_bits(resize::<u128, T>(value) << lsedge);
+				//  Discard the now-written bits from the value.
+				shift_out(&mut value, width);
+			}
+			//  Write into the body elements, from high address to low, from the
+			//  value.
+			for elt in body.unwrap_or(&[]).iter().rev() {
+				elt.store(resize(value));
+				shift_out(&mut value, T::BITS);
+			}
+			//  If the head exists, it contains the most significant chunk of
+			//  the value, on the LSedge side.
+			if let Some((h, head)) = head {
+				//  Find the region width.
+				let width = T::BITS - *h;
+				//  Erase the region.
+				head.clear_bits(T::TRUE << width);
+				//  Write the value’s region-width LSedge bits into the region.
+				let val = resize::<u128, T>(value) & mask_for(width as usize);
+				head.set_bits(val);
+			}
+		},
+	}
+}
+
+/// Fallible field access.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Loads the bits of the slice into an element, without panicking.
+	///
+	/// This is the fallible counterpart to [`BitField::load`].
+	///
+	/// # Returns
+	///
+	/// A `U` value whose least `self.len()` significant bits are filled with
+	/// the bits of `self`.
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::FieldWidth`] if `self` is empty, or wider than a
+	/// single `U` element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::error::BitError;
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34];
+	/// let bits = data.bits::<Lsb0>();
+	/// assert_eq!(bits[.. 8].try_load::<u8>(), Ok(0x12));
+	/// assert_eq!(
+	///     bits.try_load::<u8>(),
+	///     Err(BitError::FieldWidth { len: 16, width: 8 }),
+	/// );
+	/// ```
+	///
+	/// [`BitError::FieldWidth`]: ../error/enum.BitError.html
+	/// [`BitField::load`]: ../fields/trait.BitField.html#method.load
+	pub fn try_load<U>(&self) -> Result<U, BitError>
+	where U: BitStore {
+		check_width::<U>(self.len())?;
+		Ok(self.load())
+	}
+
+	/// Stores an element into the bits of the slice, without panicking.
+	///
+	/// This is the fallible counterpart to [`BitField::store`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: A value, whose `self.len()` least significant bits will be
+	///   stored into `self`.
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::FieldWidth`], and leaves the slice unchanged, if
+	/// `self` is empty, or wider than a single `U` element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::error::BitError;
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.bits_mut::<Lsb0>();
+	/// assert!(bits[4 .. 12].try_store(0xA5u8).is_ok());
+	/// assert_eq!(
+	///     bits[4 .. 4].try_store(0u8),
+	///     Err(BitError::FieldWidth { len: 0, width: 8 }),
+	/// );
+	/// assert_eq!(data, 0x0A50);
+	/// ```
+	///
+	/// [`BitError::FieldWidth`]: ../error/enum.BitError.html
+	/// [`BitField::store`]: ../fields/trait.BitField.html#method.store
+	pub fn try_store<U>(&mut self, value: U) -> Result<(), BitError>
+	where U: BitStore {
+		check_width::<U>(self.len())?;
+		self.store(value);
+		Ok(())
+	}
+}
+
+/// Checks that a region can transfer a `U` value.
+fn check_width<U>(len: usize) -> Result<(), BitError>
+where U: BitStore {
+	let width = U::BITS as usize;
+	if (1 ..= width).contains(&len) {
+		Ok(())
+	}
+	else {
+		Err(BitError::FieldWidth { len, width })
+	}
+}
+
+impl<O, V> BitField for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	BitSlice<O, V::Store>: BitField,
+{
+	fn load_le<U>(&self) -> U
+	where U: BitStore {
+		self.as_bitslice().load_le()
+	}
+
+	fn load_be<U>(&self) -> U
+	where U: BitStore {
+		self.as_bitslice().load_be()
+	}
+
+	fn store_le<U>(&mut self, value: U)
+	where U: BitStore {
+		self.as_mut_bitslice().store_le(value)
+	}
+
+	fn store_be<U>(&mut self, value: U)
+	where U: BitStore {
+		self.as_mut_bitslice().store_be(value)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> BitField for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	fn load_le<U>(&self) -> U
+	where U: BitStore {
+		self.as_bitslice().load_le()
+	}
+
+	fn load_be<U>(&self) -> U
+	where U: BitStore {
+		self.as_bitslice().load_be()
+	}
+
+	fn store_le<U>(&mut self, value: U)
+	where U: BitStore {
+		self.as_mut_bitslice().store_le(value)
+	}
+
+	fn store_be<U>(&mut self, value: U)
+	where U: BitStore {
+		self.as_mut_bitslice().store_be(value)
+	}
 }
 
-impl<T> BitField for BitSlice<Lsb0, T>
-where T: BitStore
+#[cfg(feature = "alloc")]
+impl<O, T> BitField for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
 {
 	fn load_le<U>(&self) -> U
 	where U: BitStore {
-		let len = self.len();
-		if !(1 ..= U::BITS as usize).contains(&len) {
-			panic!("Cannot load {} bits from a {}-bit region", U::BITS, len);
-		}
-
-		match self.bitptr().domain().splat() {
-			/* The live bits are in the interior of a single element.
-
-			This path only needs to load the element, shift it right by the
-			distance from LSedge to the live region, and mask it for the length
-			of `self`.
-			*/
-			Either::Right((head, elt, _)) => {
-				resize((elt.load() >> *head) & mask_for::<T>(len))
-			},
-			/* The live region touches at least one element edge.
-
-			This block reads chunks from the slice memory into an accumulator,
-			from the most-significant chunk to the least-significant. Each read
-			must collect the live section of the chunk into a temporary, then
-			shift the accumulator left by the chunk’s bit width, then write the
-			temporary into the newly-vacated least significant bits of the
-			accumulator.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut accum = 0usize;
-
-				//  If the tail exists, it contains the most significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((tail, t)) = tail {
-					//  Load, mask, resize, and store. No other data is present.
-					accum = resize(tail.load() & mask_for::<T>(*t as usize));
-				}
-				//  Read the body elements, from high address to low, into the
-				//  accumulator.
-				if let Some(elts) = body {
-					for elt in elts.iter().rev() {
-						let val: usize = resize(elt.load());
-						accum <<= T::BITS;
-						accum |= val;
-					}
-				}
-				//  If the head exists, it contains the least significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((h, head)) = head {
-					//  Get the live region’s distance from the LSedge.
-					let lsedge = *h;
-					//  Find the region width (MSedge to head).
-					let width = T::BITS - lsedge;
-					//  Load the element, shift down to LSedge, and resize.
-					let val: usize = resize(head.load() >> lsedge);
-					accum <<= width;
-					accum |= val;
-				}
-
-				resize(accum)
-			},
-		}
+		self.as_bitslice().load_le()
 	}
 
 	fn load_be<U>(&self) -> U
 	where U: BitStore {
-		let len = self.len();
-		if !(1 ..= U::BITS as usize).contains(&len) {
-			panic!("Cannot load {} bits from a {}-bit region", U::BITS, len);
-		}
-
-		match self.bitptr().domain().splat() {
-			/* The live bits are in the interior of a single element.
-
-			This path only needs to load the element, shift it right by the
-			distance from LSedge to the live region, and mask it for the length
-			of `self`.
-			*/
-			Either::Right((head, elt, _)) => {
-				resize((elt.load() >> *head) & mask_for::<T>(len))
-			},
-			/* The live region touches at least one element edge.
-
-			This block reads chunks from the slice memory into an accumulator,
-			from the most-significant chunk to the least-significant. Each read
-			must collect the live section of the chunk into a temporary, then
-			shift the accumulator left by the chunk’s width, then write the
-			temporary into the newly-vacated least significant bits of the
-			accumulator.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut accum = 0usize;
-
-				//  If the head exists, it contains the most significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((h, head)) = head {
-					//  Load, move, resize, and store. No other data is present.
-					accum = resize(head.load() >> *h);
-				}
-				//  Read the body elements, from low address to high, into the
-				//  accumulator.
-				if let Some(elts) = body {
-					for elt in elts.iter() {
-						let val: usize = resize(elt.load());
-						accum <<= T::BITS;
-						accum |= val;
-					}
-				}
-				//  If the tail exists, it contains the least significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((tail, t)) = tail {
-					//  Get the live region’s width.
-					let width = *t as usize;
-					//  Load, mask, and resize.
-					let val: usize = resize(tail.load() & mask_for::<T>(width));
-					//  Shift the accumulator by the live width, and store.
-					accum <<= width;
-					accum |= val;
-				}
-
-				resize(accum)
-			},
-		}
+		self.as_bitslice().load_be()
 	}
 
 	fn store_le<U>(&mut self, value: U)
 	where U: BitStore {
-		let len = self.len();
-		if !(1 ..= U::BITS as usize).contains(&len) {
-			panic!("Cannot store {} bits in a {}-bit region", U::BITS, len);
-		}
-
-		let value = value & mask_for(len);
-		match self.bitptr().domain().splat() {
-			/* The live region is in the interior of a single element.
-
-			The `value` is shifted left by the region’s distance from the
-			LSedge, then written directly into place.
-			*/
-			Either::Right((head, elt, _)) => {
-				//  Get the region’s distance from the LSedge.
-				let lsedge = *head;
-				//  Erase the live region.
-				elt.clear_bits(!(mask_for::<T>(len) << lsedge));
-				//  Shift the value to fit the region, and write.
-				elt.set_bits(resize::<U, T>(value) << lsedge);
-			},
-			/* The live region touches at least one element edge.
-
-			This block writes chunks from the value into slice memory, from the
-			least-significant chunk to the most-significant. Each write moves
-			a slice chunk’s width of bits from the LSedge of the value into
-			memory, then shifts the value right by that width.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut value: usize = resize(value);
-
-				//  If the head exists, it contains the least significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((h, head)) = head {
-					//  Get the region distance from the LSedge.
-					let lsedge = *h;
-					//  Find the region width (MSedge to head).
-					let width = T::BITS - lsedge;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					head.clear_bits(T::TRUE >> width);
-					//  Shift the snippet to fit the region, and write.
-					head.set_bits(resize::<usize, T>(val) << lsedge);
-					//  Discard the now-written bits from the value.
-					value >>= width;
-				}
-				//  Write into the body elements, from low address to high, from
-				//  the value.
-				if let Some(elts) = body {
-					for elt in elts.iter() {
-						elt.store(resize(value));
-						value >>= T::BITS;
-					}
-				}
-				//  If the tail exists, it contains the most significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((tail, t)) = tail {
-					//  Get the region width.
-					let width = *t;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					tail.clear_bits(T::TRUE << width);
-					//  Write the snippet into the region.
-					tail.set_bits(resize(val));
-				}
-			},
-		}
+		self.as_mut_bitslice().store_le(value)
 	}
 
 	fn store_be<U>(&mut self, value: U)
 	where U: BitStore {
-		let len = self.len();
-		if !(1 ..= U::BITS as usize).contains(&len) {
-			panic!("Cannot store {} bits in a {}-bit region", U::BITS, len);
-		}
-
-		let value = value & mask_for(len);
-		match self.bitptr().domain().splat() {
-			/* The live region is in the interior of a single element.
-
-			The `value` is shifted left by the region’s distance from the
-			LSedge, then written directly into place.
-			*/
-			Either::Right((head, elt, _)) => {
-				//  Get the region’s distance from the LSedge.
-				let lsedge = *head;
-				//  Erase the live region.
-				elt.clear_bits(!(mask_for::<T>(len) << lsedge));
-				//  Shift the value to fit the region, and write.
-				elt.set_bits(resize::<U, T>(value) << lsedge);
-			},
-			Either::Left((head, body, tail)) => {
-				let mut value: usize = resize(value);
-
-				//  If the tail exists, it contains the least significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((tail, t)) = tail {
-					//  Get the region width.
-					let width = *t;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					tail.clear_bits(T::TRUE << width);
-					//  Write the snippet into the region.
-					tail.set_bits(resize(val));
-					//  Discard the now-written bits from the value.
-					value >>= width;
-				}
-				//  Write into the body elements, from high address to low, from
-				//  the value.
-				if let Some(elts) = body {
-					for elt in elts.iter().rev() {
-						elt.store(resize(value));
-						value >>= T::BITS;
-					}
-				}
-				//  If the head exists, it contains the most significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((h, head)) = head {
-					//  Get the region distance from the LSedge.
-					let lsedge = *h;
-					//  Find the region width (MSedge to head).
-					let width = T::BITS - lsedge;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					head.clear_bits(T::TRUE >> width);
-					//  Shift the snippet to fit the region, and write.
-					head.set_bits(resize::<usize, T>(val) << lsedge);
-				}
-			},
-		}
+		self.as_mut_bitslice().store_be(value)
 	}
 }
 
-impl<T> BitField for BitSlice<Msb0, T>
-where T: BitStore
+/** Field access for volatile register views.
+
+Each transfer reads every register under the view once, in ascending address
+order, and performs the `BitSlice` transfer on the local copy. A store then
+writes every one of those registers back once, in the same order, so that the
+bits of the registers outside the view keep the values that were read.
+**/
+impl<O, T> BitField for VolatileBitSlice<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
 {
 	fn load_le<U>(&self) -> U
 	where U: BitStore {
@@ -545,60 +1588,7 @@ where T: BitStore
 		if !(1 ..= U::BITS as usize).contains(&len) {
 			panic!("Cannot load {} bits from a {}-bit region", U::BITS, len);
 		}
-
-		match self.bitptr().domain().splat() {
-			/* The live bits are in the interior of a single element.
-
-			This path only needs to load the element, shift it right by the
-			distance from LSedge to the live region, and mask it for the length
-			of `self`.
-			*/
-			Either::Right((_, elt, tail)) => {
-				resize((elt.load() >> (T::BITS - *tail)) & mask_for::<T>(len))
-			},
-			/* The live region touches at least one element edge.
-
-			This block reads chunks from the slice memory into an accumulator,
-			from the most-significant chunk to the least-significant. Each read
-			must collect the live section of the chunk into a temporary, then
-			shift the accumulator left by the chunk’s bit width, then write the
-			temporary into the newly-vacated least significant bits of the
-			accumulator.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut accum = 0usize;
-
-				//  If the tail exists, it contains the most significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((tail, t)) = tail {
-					//  Find the live region’s distance from the LSedge.
-					let lsedge = T::BITS - *t;
-					//  Load, move, resize, and store. No other data is present.
-					accum = resize(tail.load() >> lsedge);
-				}
-				//  Read the body elements, from high address to low, into the
-				//  accumulator.
-				if let Some(elts) = body {
-					for elt in elts.iter().rev() {
-						let val: usize = resize(elt.load());
-						accum <<= T::BITS;
-						accum |= val;
-					}
-				}
-				//  If the head exists, it contains the least significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((h, head)) = head {
-					//  Find the region width (head to LSedge).
-					let width = (T::BITS - *h) as usize;
-					//  Load the element, mask, and resize.
-					let val: usize = resize(head.load() & mask_for::<T>(width));
-					accum <<= width;
-					accum |= val;
-				}
-
-				resize(accum)
-			},
-		}
+		self.read_field(|bits| bits.load_le())
 	}
 
 	fn load_be<U>(&self) -> U
@@ -607,60 +1597,7 @@ where T: BitStore
 		if !(1 ..= U::BITS as usize).contains(&len) {
 			panic!("Cannot load {} bits from a {}-bit region", U::BITS, len);
 		}
-
-		match self.bitptr().domain().splat() {
-			/* The live bits are in the interior of a single element.
-
-			This path only needs to load the element, shift it right by the
-			distance from LSedge to the live region, and mask it for the length
-			of `self`.
-			*/
-			Either::Right((_, elt, tail)) => {
-				resize((elt.load() >> (T::BITS - *tail)) & mask_for::<T>(len))
-			},
-			/* The live region touches at least one element edge.
-
-			This block reads chunks from the slice memory into an accumulator,
-			from the most-significant chunk to the least-significant. Each read
-			must collect the live section of the chunk into a temporary, then
-			shift the accumulator left by the chunk’s bit width, then write the
-			temporary into the newly-vacated least significant bits of the
-			accumulator.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut accum = 0usize;
-
-				//  If the head exists, it contains the most significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((h, head)) = head {
-					//  Find the region width (head to LSedge).
-					let width = T::BITS - *h;
-					//  Load, mask, resize, and store. No other data is present.
-					accum = resize(head.load() & mask_for::<T>(width as usize));
-				}
-				//  Read the body elements, from low address to high, into the
-				//  accumulator.
-				if let Some(elts) = body {
-					for elt in elts.iter() {
-						let val: usize = resize(elt.load());
-						accum <<= T::BITS;
-						accum |= val;
-					}
-				}
-				//  If the tail exists, it contains the least significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((tail, t)) = tail {
-					//  Find the live region’s distance from LSedge.
-					let lsedge = T::BITS - *t;
-					//  Load the element, shift down to LSedge, and resize.
-					let val: usize = resize(tail.load() >> lsedge);
-					accum <<= *t;
-					accum |= val;
-				}
-
-				resize(accum)
-			},
-		}
+		self.read_field(|bits| bits.load_be())
 	}
 
 	fn store_le<U>(&mut self, value: U)
@@ -669,70 +1606,7 @@ where T: BitStore
 		if !(1 ..= U::BITS as usize).contains(&len) {
 			panic!("Cannot store {} bits in a {}-bit region", U::BITS, len);
 		}
-
-		let value = value & mask_for(len);
-		match self.bitptr().domain().splat() {
-			/* The live region is in the interior of a single element.
-
-			The `value` is shifted left by the region’s distance from the
-			LSedge, then written directly into place.
-			*/
-			Either::Right((_, elt, tail)) => {
-				//  Get the region’s distance from the LSedge.
-				let lsedge = T::BITS - *tail;
-				//  Erase the live region.
-				elt.clear_bits(!(mask_for::<T>(len) << lsedge));
-				//  Shift the value to fit the region, and write.
-				elt.set_bits(resize::<U, T>(value) << lsedge);
-			},
-			/* The live region touches at least one element edge.
-
-			This block writes chunks from the value into slice memory, from the
-			least-significant chunk to the most-significant. Each write moves a
-			slice chunk’s width of bits from the LSedge of the value into
-			memory, then shifts the value right by that width.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut value: usize = resize(value);
-
-				//  If the head exists, it contains the least significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((h, head)) = head {
-					//  Get the region width (head to LSedge).
-					let width = T::BITS - *h;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					head.clear_bits(T::TRUE << width);
-					//  Write the snippet into the region.
-					head.set_bits(resize(val));
-					//  Discard the now-written bits from the value.
-					value >>= width;
-				}
-				//  Write into the body elements, from low address to high, from
-				//  the value.
-				if let Some(elts) = body {
-					for elt in elts.iter() {
-						elt.store(resize(value));
-						value >>= T::BITS;
-					}
-				}
-				//  If the tail exists, it contains the most significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((tail, t)) = tail {
-					//  Get the region width.
-					let width = *t;
-					//  Find the region distance from the LSedge.
-					let lsedge = T::BITS - width;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					tail.clear_bits(T::TRUE >> width);
-					//  Shift the snippet to fit the region, and write.
-					tail.set_bits(resize::<usize, T>(val) << lsedge);
-				}
-			},
-		}
+		self.modify_field(|bits| bits.store_le(value));
 	}
 
 	fn store_be<U>(&mut self, value: U)
@@ -741,127 +1615,394 @@ where T: BitStore
 		if !(1 ..= U::BITS as usize).contains(&len) {
 			panic!("Cannot store {} bits in a {}-bit region", U::BITS, len);
 		}
-
-		let value = value & mask_for(len);
-		match self.bitptr().domain().splat() {
-			/* The live region is in the interior of a single element.
-
-			The `value` is shifted left by the region’s distance from the
-			LSedge, then written directly into place.
-			*/
-			Either::Right((_, elt, tail)) => {
-				//  Get the region’s distance from the LSedge.
-				let lsedge = T::BITS - *tail;
-				//  Erase the live region.
-				elt.clear_bits(!(mask_for::<T>(len) << lsedge));
-				//  Shift the value to fit the region, and write.
-				elt.set_bits(resize::<U, T>(value) << lsedge);
-			},
-			/* The live region touches at least one element edge.
-
-			This block writes chunks from the value into slice memory, from the
-			least-significant chunk to the most-significant. Each write moves a
-			slice chunk’s width of bits from the LSedge of the value into
-			memory, then shifts the value right by that width.
-			*/
-			Either::Left((head, body, tail)) => {
-				let mut value: usize = resize(value);
-
-				//  If the tail exists, it contains the least significant chunk
-				//  of the value, on the MSedge side.
-				if let Some((tail, t)) = tail {
-					//  Get the region width (MSedge to tail).
-					let width = *t;
-					//  Find the region distance from the LSedge.
-					let lsedge = T::BITS - width;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					tail.clear_bits(T::TRUE >> width);
-					//  Shift the snippet to fit the region, and write.
-					tail.set_bits(resize::<usize, T>(val) << lsedge);
-					//  Discard the now-written bits from the value.
-					value >>= width;
-				}
-				//  Write into the body elements, from high address to low, from
-				//  the value.
-				if let Some(elts) = body {
-					for elt in elts.iter().rev() {
-						elt.store(resize(value));
-						value >>= T::BITS;
-					}
-				}
-				//  If the head exists, it contains the most significant chunk
-				//  of the value, on the LSedge side.
-				if let Some((h, head)) = head {
-					//  Find the region width.
-					let width = T::BITS - *h;
-					//  Take the region-width LSedge bits of the value.
-					let val = value & mask_for::<usize>(width as usize);
-					//  Erase the region.
-					head.clear_bits(T::TRUE << width);
-					//  Write the snippet into the region.
-					head.set_bits(resize(val));
-				}
-			},
-		}
+		self.modify_field(|bits| bits.store_be(value));
 	}
 }
 
-#[cfg(feature = "alloc")]
-impl<O, T> BitField for BitBox<O, T>
+/// A partially-live element of a slice, and the mask of its live bits.
+type Edge<'a, T> = Option<(&'a <T as BitStore>::Access, T)>;
+
+/** Splits a slice into its partially-live edge elements and its fully-live
+body elements.
+
+# Parameters
+
+- `bits`: Any bit slice.
+
+# Returns
+
+- `.0`: The lowest-addressed element, if it is only partially live, and the
+  mask of its live bits. If the slice lies in the interior of one element, that
+  element is produced here.
+- `.1`: The fully-live elements.
+- `.2`: The highest-addressed element, if it is only partially live, and the
+  mask of its live bits.
+**/
+fn live_elements<O, T>(
+	bits: &BitSlice<O, T>,
+) -> (Edge<T>, &[T::Access], Edge<T>)
 where
 	O: BitOrder,
 	T: BitStore,
-	BitSlice<O, T>: BitField,
 {
-	fn load_le<U>(&self) -> U
-	where U: BitStore {
-		self.as_bitslice().load_le()
+	match bits.bitptr().domain().splat() {
+		Either::Right((h, elt, t)) => {
+			(Some((elt, O::mask_range(h, t))), &[], None)
+		},
+		Either::Left((head, body, tail)) => (
+			head.map(|(h, elt)| (elt, O::mask_range(h, T::BITS.tail()))),
+			body.unwrap_or(&[]),
+			tail.map(|(elt, t)| (elt, O::mask_range(0.idx(), t))),
+		),
 	}
+}
 
-	fn load_be<U>(&self) -> U
-	where U: BitStore {
-		self.as_bitslice().load_be()
+/** Moves the live bits of an element into the LSedge of an accumulator.
+
+# Parameters
+
+- `accum`: The accumulator. Its contents are shifted up by the number of live
+  bits in `mask`, and those bits, packed in order of significance, fill the
+  vacated LSedge.
+- `elt`: An element loaded from memory.
+- `mask`: The live bits of `elt`.
+**/
+fn push_chunk<T>(accum: &mut u128, elt: T, mask: T)
+where T: BitStore {
+	let width = mask.count_ones();
+	shift_in(accum, resize(extract(elt, mask)), width as u8);
+}
+
+/** Moves the LSedge of a value into the live bits of an element.
+
+# Parameters
+
+- `value`: The value being stored. The number of live bits in `mask` are taken
+  from its LSedge, and it is then shifted down by that number.
+- `elt`: An element in memory. Its live bits are overwritten, and its dead bits
+  are left unchanged.
+- `mask`: The live bits of `elt`.
+**/
+fn pop_chunk<T>(value: &mut u128, elt: &T::Access, mask: T)
+where T: BitStore {
+	let width = mask.count_ones();
+	let chunk = deposit(resize(*value & mask_for::<u128>(width)), mask);
+	if mask == T::TRUE {
+		elt.store(chunk);
+	}
+	else {
+		elt.clear_bits(!mask);
+		elt.set_bits(chunk);
 	}
+	shift_out(value, width as u8);
+}
 
-	fn store_le<U>(&mut self, value: U)
-	where U: BitStore {
-		self.as_mut_bitslice().store_le(value)
+/** Shifts a chunk into the LSedge of an accumulator.
+
+# Parameters
+
+- `accum`: The accumulator. Its contents are shifted up by `width`, which may be
+  the full width of a `u128`.
+- `chunk`: The bits to place in the vacated LSedge. It must have no bits set at
+  or above `width`.
+- `width`: The width of `chunk`.
+**/
+fn shift_in(accum: &mut u128, chunk: u128, width: u8) {
+	*accum = accum.checked_shl(width as u32).unwrap_or(0) | chunk;
+}
+
+/** Discards the bits of a value that have been written to memory.
+
+# Parameters
+
+- `value`: The value being stored. It is shifted down by `width`, which may be
+  the full width of a `u128`.
+- `width`: The number of bits taken from the LSedge of `value`.
+**/
+fn shift_out(value: &mut u128, width: u8) {
+	*value = value.checked_shr(width as u32).unwrap_or(0);
+}
+
+/** Loads a field of any width into a slice of words.
+
+# Parameters
+
+- `bits`: The field.
+- `big`: Selects big-endian element significance, rather than little-endian.
+- `words`: The destination, which is cleared and then filled from the least
+  significant bit of the field upwards.
+- `rev`: Places the least significant word at the end of `words`, rather than
+  the start.
+
+# Panics
+
+This panics if `bits` is empty or wider than `words`.
+**/
+fn gather<O, T, U>(bits: &BitSlice<O, T>, big: bool, words: &mut [U], rev: bool)
+where
+	O: BitOrder,
+	T: BitStore,
+	U: BitStore,
+{
+	let len = bits.len();
+	let width = words.len() * U::BITS as usize;
+	if !(1 ..= width).contains(&len) {
+		panic!("Cannot load {} bits from a {}-bit region", width, len);
+	}
+	for word in words.iter_mut() {
+		*word = U::FALSE;
 	}
 
-	fn store_be<U>(&mut self, value: U)
-	where U: BitStore {
-		self.as_mut_bitslice().store_be(value)
+	//  Visit the chunks from least significant to most, placing each just
+	//  above the last.
+	let mut offset = 0;
+	let mut take = |elt: T, mask: T| {
+		let chunk: u128 = resize(extract(elt, mask));
+		let width = mask.count_ones();
+		write_span(words, rev, offset, chunk, width);
+		offset += width;
+	};
+	let (head, body, tail) = live_elements(bits);
+	if big {
+		if let Some((elt, mask)) = tail {
+			take(elt.load(), mask);
+		}
+		for elt in body.iter().rev() {
+			take(elt.load(), T::TRUE);
+		}
+		if let Some((elt, mask)) = head {
+			take(elt.load(), mask);
+		}
+	}
+	else {
+		if let Some((elt, mask)) = head {
+			take(elt.load(), mask);
+		}
+		for elt in body.iter() {
+			take(elt.load(), T::TRUE);
+		}
+		if let Some((elt, mask)) = tail {
+			take(elt.load(), mask);
+		}
 	}
 }
 
-#[cfg(feature = "alloc")]
-impl<O, T> BitField for BitVec<O, T>
+/** Stores a field of any width from a slice of words.
+
+This is the inverse of `gather`, and takes the same parameters.
+
+# Panics
+
+This panics if `bits` is empty or wider than `words`.
+**/
+fn scatter<O, T, U>(bits: &mut BitSlice<O, T>, big: bool, words: &[U], rev: bool)
 where
 	O: BitOrder,
 	T: BitStore,
-	BitSlice<O, T>: BitField,
+	U: BitStore,
 {
-	fn load_le<U>(&self) -> U
-	where U: BitStore {
-		self.as_bitslice().load_le()
+	let len = bits.len();
+	let width = words.len() * U::BITS as usize;
+	if !(1 ..= width).contains(&len) {
+		panic!("Cannot store {} bits in a {}-bit region", width, len);
 	}
 
-	fn load_be<U>(&self) -> U
-	where U: BitStore {
-		self.as_bitslice().load_be()
+	let mut offset = 0;
+	let mut put = |elt: &T::Access, mask: T| {
+		let width = mask.count_ones();
+		let chunk = deposit(resize(read_span(words, rev, offset, width)), mask);
+		if mask == T::TRUE {
+			elt.store(chunk);
+		}
+		else {
+			elt.clear_bits(!mask);
+			elt.set_bits(chunk);
+		}
+		offset += width;
+	};
+	let (head, body, tail) = live_elements(bits);
+	if big {
+		if let Some((elt, mask)) = tail {
+			put(elt, mask);
+		}
+		for elt in body.iter().rev() {
+			put(elt, T::TRUE);
+		}
+		if let Some((elt, mask)) = head {
+			put(elt, mask);
+		}
+	}
+	else {
+		if let Some((elt, mask)) = head {
+			put(elt, mask);
+		}
+		for elt in body.iter() {
+			put(elt, T::TRUE);
+		}
+		if let Some((elt, mask)) = tail {
+			put(elt, mask);
+		}
 	}
+}
 
-	fn store_le<U>(&mut self, value: U)
-	where U: BitStore {
-		self.as_mut_bitslice().store_le(value)
+/** Writes the low bits of a value into a span of a word slice.
+
+# Parameters
+
+- `words`: A slice of words, treated as one integer. Its bits in the span must
+  be clear.
+- `rev`: Treats the last word as least significant, rather than the first.
+- `offset`: The significance of the lowest bit of the span.
+- `value`: The bits to write.
+- `width`: The width of the span.
+**/
+fn write_span<U>(
+	words: &mut [U],
+	rev: bool,
+	mut offset: usize,
+	mut value: u128,
+	mut width: usize,
+) where
+	U: BitStore,
+{
+	let bits = U::BITS as usize;
+	let last = words.len() - 1;
+	while width > 0 {
+		let (idx, shift) = (offset / bits, offset % bits);
+		let step = cmp::min(width, bits - shift);
+		let part: U = resize(value & mask_for::<u128>(step));
+		words[if rev { last - idx } else { idx }] |= part << shift as u8;
+		value = value.checked_shr(step as u32).unwrap_or(0);
+		offset += step;
+		width -= step;
 	}
+}
 
-	fn store_be<U>(&mut self, value: U)
-	where U: BitStore {
-		self.as_mut_bitslice().store_be(value)
+/** Reads a span of a word slice into the low bits of a value.
+
+This is the inverse of `write_span`, and takes the same parameters.
+
+# Returns
+
+The bits of the span, against the LSedge.
+**/
+fn read_span<U>(words: &[U], rev: bool, offset: usize, width: usize) -> u128
+where U: BitStore {
+	let bits = U::BITS as usize;
+	let last = words.len() - 1;
+	let mut out = 0u128;
+	let mut done = 0;
+	while done < width {
+		let (idx, shift) = ((offset + done) / bits, (offset + done) % bits);
+		let step = cmp::min(width - done, bits - shift);
+		let word = words[if rev { last - idx } else { idx }];
+		let part: u128 = resize((word >> shift as u8) & mask_for::<U>(step));
+		out |= part << done;
+		done += step;
+	}
+	out
+}
+
+/// Joins four words, least significant first, into a `u128`.
+fn join_u128(words: [u32; 4]) -> u128 {
+	words
+		.iter()
+		.rev()
+		.fold(0, |accum, &word| accum << 32 | u128::from(word))
+}
+
+/// Splits a `u128` into four words, least significant first.
+fn split_u128(value: u128) -> [u32; 4] {
+	let mut words = [0u32; 4];
+	for (n, word) in words.iter_mut().enumerate() {
+		*word = (value >> (32 * n)) as u32;
+	}
+	words
+}
+
+/** Tests if the set bits of a mask are all adjacent.
+
+# Returns
+
+The distance from the LSedge to the lowest set bit of `mask`, if its set bits
+form a single run. Zero masks are not contiguous.
+**/
+fn contiguous<T>(mask: T) -> Option<u8>
+where T: BitStore {
+	let low = mask.trailing_zeros();
+	let span = low + mask.count_ones() + mask.leading_zeros();
+	if mask != T::FALSE && span == T::BITS as usize {
+		Some(low as u8)
+	}
+	else {
+		None
+	}
+}
+
+/** Gathers the bits of an element selected by a mask into its LSedge.
+
+# Parameters
+
+- `elt`: Any element.
+- `mask`: The bits of `elt` to gather.
+
+# Returns
+
+The bits of `elt` under `mask`, in their order of significance, packed against
+the LSedge.
+**/
+fn extract<T>(elt: T, mask: T) -> T
+where T: BitStore {
+	if let Some(low) = contiguous(mask) {
+		return (elt & mask) >> low;
+	}
+	let one = !(T::TRUE << 1);
+	let mut out = T::FALSE;
+	let mut width = 0;
+	for n in 0 .. T::BITS {
+		let bit = one << n;
+		if mask & bit != T::FALSE {
+			if elt & bit != T::FALSE {
+				out |= one << width;
+			}
+			width += 1;
+		}
+	}
+	out
+}
+
+/** Scatters the LSedge bits of a value into the bits selected by a mask.
+
+This is the inverse of `extract`.
+
+# Parameters
+
+- `value`: A value whose low bits, one per set bit of `mask`, are to be placed.
+- `mask`: The bits of the output that receive `value`.
+
+# Returns
+
+An element with the low bits of `value` placed, in order of significance, into
+the set bits of `mask`, and all other bits zero.
+**/
+fn deposit<T>(value: T, mask: T) -> T
+where T: BitStore {
+	if let Some(low) = contiguous(mask) {
+		return (value << low) & mask;
+	}
+	let one = !(T::TRUE << 1);
+	let mut out = T::FALSE;
+	let mut width = 0;
+	for n in 0 .. T::BITS {
+		let bit = one << n;
+		if mask & bit != T::FALSE {
+			if value & (one << width) != T::FALSE {
+				out |= bit;
+			}
+			width += 1;
+		}
 	}
+	out
 }
 
 /** Safely computes an LS-edge bitmask for a value of some length.
@@ -895,12 +2036,58 @@ where T: BitStore {
 	}
 }
 
+/** Sign-extends a value from the top bit of its live region.
+
+# Parameters
+
+- `value`: A value whose bits above the lowest `len` are all zero.
+- `len`: The width of the live region of `value`. This must not be zero.
+
+# Returns
+
+`value`, with all bits above the lowest `len` set if bit `len - 1` is set.
+**/
+fn sign_extend<U>(value: U, len: usize) -> U
+where U: BitStore {
+	if value & !mask_for::<U>(len - 1) != U::FALSE {
+		value | !mask_for::<U>(len)
+	}
+	else {
+		value
+	}
+}
+
+/** Checks that a signed value fits in a region, and reinterprets it.
+
+# Parameters
+
+- `value`: A signed value to be stored.
+- `len`: The width of the region that will store `value`.
+
+# Returns
+
+The unsigned bit pattern of `value`.
+
+# Panics
+
+This panics if `len` is zero, or if `value` is outside the range of a
+`len`-bit two’s-complement integer.
+**/
+fn check_signed<I>(value: I, len: usize) -> I::Unsigned
+where I: Signed {
+	let bits = value.to_unsigned();
+	if len == 0 || sign_extend(bits & mask_for(len), len) != bits {
+		panic!("Cannot store {:?} in a {}-bit signed region", value, len);
+	}
+	bits
+}
+
 /** Resizes a value from one fundamental type to another.
 
-This function uses `usize` as the intermediate type (as it is the largest
-`BitStore` implementor on all supported targets), and either zero-extends or
-truncates the source value to be valid as the destination type. This is
-essentially a generic-aware version of the `as` operator.
+This function copies the significant bytes of the source value into the
+destination type, and so either zero-extends or truncates the source value to
+be valid as the destination type. This is essentially a generic-aware version of
+the `as` operator.
 
 # Parameters
 
@@ -1123,6 +2310,130 @@ mod tests {
 		}
 		*/
 	}
+
+	#[test]
+	fn signed() {
+		let mut data = [0u8; 4];
+		let bits = data.bits_mut::<Msb0>();
+
+		for &val in &[-16i8, -1, 0, 1, 15] {
+			bits[3 .. 8].store_le_signed(val);
+			assert_eq!(bits[3 .. 8].load_le_signed::<i8>(), val);
+			assert_eq!(bits[3 .. 8].load_le_signed::<i32>(), val as i32);
+		}
+		bits[3 .. 8].store_signed(-16i8);
+		assert_eq!(bits[3 .. 8].load::<u8>(), 0b10000);
+
+		//  Spanning fields sign-extend from the most significant element.
+		bits[4 .. 20].store_be_signed(-2i16);
+		assert_eq!(bits[4 .. 20].load_be_signed::<i16>(), -2);
+		assert_eq!(bits[4 .. 20].load_be_signed::<isize>(), -2);
+		bits[4 .. 20].store_le_signed(-0x8000i16);
+		assert_eq!(bits[4 .. 20].load_le_signed::<i16>(), -0x8000i16);
+		assert_eq!(bits[4 .. 20].load_le_signed::<i32>(), -0x8000);
+
+		let mut word = 0u32;
+		let bits = word.bits_mut::<Lsb0>();
+		bits[..].store_signed(-0x8000_0000i32);
+		assert_eq!(bits.load_signed::<i32>(), -0x8000_0000i32);
+		bits[.. 1].store_signed(-1i8);
+		assert_eq!(bits[.. 1].load_signed::<i8>(), -1);
+	}
+
+	#[test]
+	fn wide() {
+		let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+		let mut data = [0u16; 10];
+		for &(from, len) in &[(0, 128), (3, 128), (5, 100), (17, 65), (9, 1)] {
+			let mask = !0u128 >> (128 - len);
+			let field = &mut data.bits_mut::<Lsb0>()[from .. from + len];
+			field.store_le_u128(value);
+			assert_eq!(field.load_le_u128(), value & mask);
+			field.store_be_u128(value);
+			assert_eq!(field.load_be_u128(), value & mask);
+			field.store_u128(!value);
+			assert_eq!(field.load_u128(), !value & mask);
+
+			let field = &mut data.bits_mut::<Msb0>()[from .. from + len];
+			field.store_be_u128(value);
+			assert_eq!(field.load_be_u128(), value & mask);
+		}
+
+		//  Narrow fields agree with `BitField`.
+		let bits = data.bits_mut::<Msb0>();
+		bits[5 .. 30].store_be(0x1AB_CDEFu32);
+		let mut words = [0u8; 4];
+		bits[5 .. 30].load_be_words(&mut words);
+		assert_eq!(words, [0x01, 0xAB, 0xCD, 0xEF]);
+		bits[5 .. 30].load_le_words(&mut words);
+		assert_eq!(bits[5 .. 30].load_le::<u32>(), u32::from_le_bytes(words));
+
+		//  Whole elements copy through unchanged in either ordering.
+		let src = [0x1111u16, 0x2222, 0x3333];
+		bits[16 .. 64].store_le_words(&src);
+		assert_eq!(&bits.as_slice()[1 .. 4], &src);
+		bits[16 .. 64].store_be_words(&[0xAAAAu16, 0xBBBB, 0xCCCC]);
+		assert_eq!(&bits.as_slice()[1 .. 4], &[0xAAAA, 0xBBBB, 0xCCCC]);
+	}
+
+	#[test]
+	fn u128_elements() {
+		let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+		let mut data = [0u128; 2];
+		let bits = data.bits_mut::<Msb0>();
+
+		bits[.. 128].store_be(value);
+		assert_eq!(bits.as_slice(), &[value, 0]);
+		bits[100 .. 228].store_le(value);
+		assert_eq!(bits[100 .. 228].load_le::<u128>(), value);
+		bits[100 .. 228].store_be(value);
+		assert_eq!(bits[100 .. 228].load_be::<u128>(), value);
+		assert_eq!(bits[100 .. 228].load_be_u128(), value);
+		assert_eq!(bits[.. 100].load_be::<u128>(), value >> 28);
+	}
+
+	#[test]
+	#[should_panic(expected = "Cannot store 16 bits in a 17-bit region")]
+	fn wide_overflow() {
+		let mut data = [0u8; 4];
+		data.bits_mut::<Lsb0>()[.. 17].store_le_words(&[0u8; 2]);
+	}
+
+	#[test]
+	fn wire_formats() {
+		//  IPv4 header from RFC 791 §3.1, with flags `DF` and fragment offset
+		//  0x0ABC, in network order.
+		let mut header = [0u8; 8];
+		let bits = header.bits_mut::<Msb0>();
+		bits[.. 4].store_be(4u8);
+		bits[4 .. 8].store_be(5u8);
+		bits[16 .. 32].store_be(1500u16);
+		bits[32 .. 48].store_be(0xC001u16);
+		bits[48 .. 51].store_be(0b010u8);
+		bits[51 .. 64].store_endian(0x0ABCu16, Endian::Big);
+		assert_eq!(header, [0x45, 0, 0x05, 0xDC, 0xC0, 0x01, 0x4A, 0xBC]);
+		let bits = header.bits::<Msb0>();
+		assert_eq!(bits[51 .. 64].load_be::<u16>(), 0x0ABC);
+		assert_eq!(bits[16 .. 32].load_endian::<u16>(Endian::Big), 1500);
+
+		//  A 12-bit CAN “Motorola” signal whose most significant bit is bit 3
+		//  of byte 0, and a 12-bit “Intel” signal whose least significant bit
+		//  is bit 4 of byte 0.
+		let mut frame = [0u8; 2];
+		frame.bits_mut::<Msb0>()[4 ..].store_be(0xABCu16);
+		assert_eq!(frame, [0x0A, 0xBC]);
+		let mut frame = [0u8; 2];
+		frame.bits_mut::<Lsb0>()[4 ..].store_le(0xABCu16);
+		assert_eq!(frame, [0xC0, 0xAB]);
+		assert_eq!(frame.bits::<Lsb0>()[4 ..].load_le::<u16>(), 0xABC);
+	}
+
+	#[test]
+	#[should_panic(expected = "Cannot store 16 in a 5-bit signed region")]
+	fn signed_overflow() {
+		let mut data = 0u8;
+		data.bits_mut::<Lsb0>()[.. 5].store_signed(16i8);
+	}
 }
 
 #[cfg(test)]
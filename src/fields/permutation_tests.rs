@@ -9,12 +9,19 @@ the six variables present in `BitField` functions:
 4. `_le` and `_be` element orderings
 5. `u8`, `u16`, `u32`, `u64` value transfer types
 6. Empty slice and too-wide slice conditions
+
+It also checks every region of a short slice, in both element orderings,
+against a bit-by-bit model of the trait’s behavior, for the two provided
+orderings and for a custom ordering whose indices are not contiguous.
 !*/
 
 #![cfg(test)]
 
 use super::*;
-use crate::prelude::*;
+use crate::{
+	order::Swizzle,
+	prelude::*,
+};
 
 #[test]
 fn check_mask() {
@@ -1726,3 +1733,113 @@ fn bsm64_sb32_full() {
 fn bsm64_sb64_full() {
 	[0u64; 2].bits_mut::<Msb0>().store_be::<u64>(0);
 }
+
+/** Lists, for a region of a slice, the bit of the loaded value that each index
+of the region holds.
+
+Each element’s live bits form one chunk of the value, in order of their
+electrical position. `_le` loads place the lowest-addressed chunk in the least
+significant bits of the value; `_be` loads place it in the most significant.
+**/
+fn model<O, T>(head: usize, len: usize, big: bool) -> Vec<usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let bits = T::BITS as usize;
+	let first = head / bits;
+	let last = (head + len - 1) / bits;
+	let mut chunks = (first ..= last)
+		.map(|elt| {
+			let mut chunk = (head .. head + len)
+				.filter(|n| n / bits == elt)
+				.map(|n| (*O::at::<T>(((n % bits) as u8).idx()), n - head))
+				.collect::<Vec<_>>();
+			chunk.sort();
+			chunk
+		})
+		.collect::<Vec<_>>();
+	if big {
+		chunks.reverse();
+	}
+	let mut out = vec![0; len];
+	for (place, (_, idx)) in chunks.into_iter().flatten().enumerate() {
+		out[idx] = place;
+	}
+	out
+}
+
+fn check_model<O, T, U>()
+where
+	O: BitOrder,
+	T: BitStore,
+	U: BitStore,
+{
+	let bits = T::BITS as usize * 3;
+	//  An arbitrary pattern with set and unset bits in every element.
+	let pattern: Vec<bool> = (0 .. bits).map(|n| n * 7 % 5 < 2).collect();
+	for head in 0 .. bits {
+		for len in 1 ..= cmp::min(U::BITS as usize, bits - head) {
+			for &big in &[false, true] {
+				let places = model::<O, T>(head, len, big);
+				let mut data = [T::FALSE; 3];
+				let slice = data.bits_mut::<O>();
+				for (n, &bit) in pattern.iter().enumerate() {
+					slice.set(n, bit);
+				}
+				let region = &mut slice[head .. head + len];
+
+				let endian = if big { Endian::Big } else { Endian::Little };
+				let value: u128 = resize(region.load_endian::<U>(endian));
+				for (idx, &place) in places.iter().enumerate() {
+					assert_eq!(
+						value >> place & 1 == 1,
+						region[idx],
+						"{} {} load: head {}, len {}, index {}",
+						O::TYPENAME,
+						if big { "be" } else { "le" },
+						head,
+						len,
+						idx,
+					);
+				}
+
+				let value = !value;
+				region.store_endian::<U>(resize(value), endian);
+				for (idx, &place) in places.iter().enumerate() {
+					assert_eq!(region[idx], value >> place & 1 == 1);
+				}
+				for n in (0 .. head).chain(head + len .. bits) {
+					assert_eq!(slice[n], pattern[n], "changed index {}", n);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn model_lsb0() {
+	check_model::<Lsb0, u8, u16>();
+	check_model::<Lsb0, u16, u32>();
+	check_model::<Lsb0, u32, u32>();
+	check_model::<Lsb0, u16, u128>();
+	check_model::<Lsb0, u128, u128>();
+}
+
+#[test]
+fn model_msb0() {
+	check_model::<Msb0, u8, u16>();
+	check_model::<Msb0, u16, u32>();
+	check_model::<Msb0, u32, u32>();
+	check_model::<Msb0, u16, u128>();
+	check_model::<Msb0, u128, u128>();
+}
+
+#[test]
+fn model_custom() {
+	check_model::<Swizzle, u8, u8>();
+	check_model::<Swizzle, u8, u16>();
+	check_model::<Swizzle, u16, u32>();
+	check_model::<Swizzle, u32, u32>();
+	check_model::<Swizzle, u128, u32>();
+}
@@ -230,13 +230,18 @@ describe is *empty*. Non-empty spans always cycle through the domain
 
 This type cannot be used for indexing, and does not translate to `BitPos<T>`.
 This type has no behavior other than viewing its internal `u8` for arithmetic.
+It is public so that [`BitOrder`] implementors can receive the end of a
+semantic range, such as in [`BitOrder::mask_range`].
 
 # Type Parameters
 
 - `T`: The memory element type controlled by this tail.
+
+[`BitOrder`]: ../order/trait.BitOrder.html
+[`BitOrder::mask_range`]: ../order/trait.BitOrder.html#method.mask_range
 **/
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub(crate) struct BitTail<T>
+pub struct BitTail<T>
 where T: BitStore
 {
 	/// Semantic index *after* an element. Constrained to `0 ..= T::BITS`.
@@ -248,6 +253,24 @@ where T: BitStore
 impl<T> BitTail<T>
 where T: BitStore
 {
+	/// Wraps a counter value as a known-good tail of the `T` element type.
+	///
+	/// # Parameters
+	///
+	/// - `end`: A semantic index at or after the end of a `T` memory element.
+	///
+	/// # Returns
+	///
+	/// If `end` is within the range `0 ..= T::BITS`, then this returns the
+	/// tail value wrapped in the tail type; if `end` exceeds this range, then
+	/// this returns `None`.
+	pub fn new(end: u8) -> Option<Self> {
+		if end > T::BITS {
+			return None;
+		}
+		Some(unsafe { Self::new_unchecked(end) })
+	}
+
 	/// Mark that `end` is a tail index for a type.
 	///
 	/// # Parameters
@@ -55,7 +55,9 @@ extern crate serde_test;
 pub mod macros;
 
 mod access;
-mod domain;
+pub mod array;
+pub mod domain;
+pub mod error;
 pub mod fields;
 pub mod indices;
 pub mod order;
@@ -63,10 +65,27 @@ mod pointer;
 pub mod prelude;
 pub mod slice;
 pub mod store;
+pub mod stream;
+pub mod volatile;
+
+#[cfg(all(feature = "alloc", feature = "atomic"))]
+pub mod bitmap;
 
 #[cfg(feature = "alloc")]
 pub mod boxed;
 
+#[cfg(feature = "alloc")]
+pub mod ewah;
+
+#[cfg(feature = "alloc")]
+pub mod rank;
+
+#[cfg(feature = "alloc")]
+pub mod roaring;
+
+#[cfg(feature = "alloc")]
+pub mod set;
+
 #[cfg(feature = "alloc")]
 pub mod vec;
 
@@ -1,6 +1,6 @@
 /*! Utility macros for constructing data structures and implementing bulk types.
 
-The public macros are `bits!`, `bitvec!`, and `bitbox!`.
+The public macros are `bits!`, `bitarr!`, `bitvec!`, and `bitbox!`.
 !*/
 
 #[macro_use]
@@ -11,10 +11,10 @@ pub mod internal;
 
 `bits!` can be invoked in a number of ways. It takes the name of a `BitOrder`
 implementation, the name of a `BitStore`-implementing fundamental (which must be
-one of `u8`, `u16`, `u32`, or `u64`), and zero or more fundamentals (integer,
-floating-point) which are used to build the bits. Each fundamental literal
-corresponds to one bit, and is considered to represent `1` if it is any other
-value than exactly zero.
+one of `u8`, `u16`, `u32`, `u64`, or `u128`), and zero or more fundamentals
+(integer, floating-point) which are used to build the bits. Each fundamental
+literal corresponds to one bit, and is considered to represent `1` if it is any
+other value than exactly zero.
 
 `bits!` can be invoked with no specifiers, a `BitOrder` specifier, or a
 `BitOrder` and a `BitStore` specifier. It cannot be invoked with a `BitStore`
@@ -116,6 +116,94 @@ macro_rules! bits {
 	};
 }
 
+/** Construct a `BitArray` out of a literal array in source code, like `bits!`.
+
+This has exactly the same syntax as [`bits!`], but rather than borrowing a
+`static` buffer, it produces a `BitArray` value which owns its storage. It does
+not require an allocator.
+
+A `BitArray` always spans every bit of its storage elements, so when the number
+of bits given is not a multiple of the element width, the produced array is
+padded out to a whole element with `0` bits.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut arr = bitarr![Msb0, u8; 0, 1, 1];
+assert_eq!(arr.len(), 8);
+arr.set(0, true);
+assert_eq!(arr.into_inner(), [0b1110_0000]);
+
+let arr = bitarr![Lsb0, u16; 1; 20];
+assert_eq!(arr.into_inner(), [!0, !0]);
+```
+
+[`bits!`]: #macro.bits
+**/
+#[macro_export]
+macro_rules! bitarr {
+	//  Sequence syntax `[bit (, bit)*]` or `[(bit ,)*]`
+
+	//  Explicit order and store.
+	($order:ident, $store:ident; $($val:expr),* $(,)?) => {
+		$crate::__bitarr_from_array!(
+			$order,
+			$crate::__bits_store_array!($order, $store; $($val),*)
+		)
+	};
+	($order:path, $store:ident; $($val:expr),* $(,)?) => {
+		$crate::__bitarr_from_array!(
+			$order,
+			$crate::__bits_store_array!($order, $store; $($val),*)
+		)
+	};
+
+	//  Explicit order, default store.
+	($order:ident; $($val:expr),* $(,)?) => {
+		$crate::bitarr!($order, usize; $($val),*)
+	};
+	($order:path; $($val:expr),* $(,)?) => {
+		$crate::bitarr!($order, usize; $($val),*)
+	};
+
+	//  Default order and store.
+	($($val:expr),* $(,)?) => {
+		$crate::bitarr!(Local, usize; $($val),*)
+	};
+
+	//  Repetition syntax `[bit ; count]`
+	//  NOTE: `count` must be `const`, as the array length is fixed.
+
+	//  Explicit order and store.
+	($order:ident, $store:ident; $val:expr; $len:expr) => {
+		$crate::__bitarr_from_array!($order, [
+			$crate::__extend_bool!($val, $store);
+			$crate::store::elts::<$store>($len)
+		])
+	};
+	($order:path, $store:ident; $val:expr; $len:expr) => {
+		$crate::__bitarr_from_array!($order, [
+			$crate::__extend_bool!($val, $store);
+			$crate::store::elts::<$store>($len)
+		])
+	};
+
+	//  Explicit order, default store.
+	($order:ident; $val:expr; $len:expr) => {
+		$crate::bitarr!($order, usize; $val; $len)
+	};
+	($order:path; $val:expr; $len:expr) => {
+		$crate::bitarr!($order, usize; $val; $len)
+	};
+
+	//  Default order and store.
+	($val:expr; $len:expr) => {
+		$crate::bitarr!(Local, usize; $val; $len)
+	};
+}
+
 /** Construct a `BitVec` out of a literal array in source code, like `vec!`.
 
 `bitvec!` can be invoked in a number of ways. It takes the name of a `BitOrder`
@@ -206,6 +294,8 @@ mod tests {
 		bits![Lsb0, u16; 0, 1];
 		bits![Msb0, u32; 0, 1];
 		bits![Lsb0, u32; 0, 1];
+		bits![Msb0, u128; 0, 1];
+		bits![Lsb0, u128; 0, 1];
 
 		#[cfg(target_pointer_width = "64")]
 		{
@@ -222,6 +312,8 @@ mod tests {
 		bits![Lsb0, u16; 1; 70];
 		bits![Msb0, u32; 0; 70];
 		bits![Lsb0, u32; 1; 70];
+		bits![Msb0, u128; 0; 70];
+		bits![Lsb0, u128; 1; 70];
 
 		#[cfg(target_pointer_width = "64")]
 		{
@@ -230,6 +322,69 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn u128_values() {
+		assert_eq!(bitarr![Msb0, u128; 1, 0, 1].as_slice(), &[5 << 125]);
+		assert_eq!(bitarr![Lsb0, u128; 1, 0, 1].as_slice(), &[5]);
+		assert_eq!(
+			crate::fields::BitField::load_le::<u8>(bits![Lsb0, u128; 1, 0, 1]),
+			5,
+		);
+
+		let arr = bitarr![Msb0, u128;
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+			1, 1,
+		];
+		assert_eq!(arr.as_slice(), &[1, 3 << 126]);
+		assert_eq!(bitarr![Lsb0, u128; 1; 130].as_slice(), &[!0, !0]);
+	}
+
+	#[test]
+	fn compile_bitarr_macros() {
+		bitarr![0, 1];
+		bitarr![Msb0; 0, 1];
+		bitarr![Lsb0; 0, 1];
+		bitarr![Msb0, u8; 0, 1];
+		bitarr![Lsb0, u8; 0, 1];
+		bitarr![Msb0, u16; 0, 1];
+		bitarr![Lsb0, u16; 0, 1];
+		bitarr![Msb0, u32; 0, 1];
+		bitarr![Lsb0, u32; 0, 1];
+		bitarr![Msb0, u128; 0, 1];
+		bitarr![Lsb0, u128; 0, 1];
+
+		#[cfg(target_pointer_width = "64")]
+		{
+			bitarr![Msb0, u64; 0, 1];
+			bitarr![Lsb0, u64; 0, 1];
+		}
+
+		bitarr![1; 70];
+		bitarr![Msb0; 0; 70];
+		bitarr![Lsb0; 1; 70];
+		bitarr![Msb0, u8; 0; 70];
+		bitarr![Lsb0, u8; 1; 70];
+		bitarr![Msb0, u16; 0; 70];
+		bitarr![Lsb0, u16; 1; 70];
+		bitarr![Msb0, u32; 0; 70];
+		bitarr![Lsb0, u32; 1; 70];
+		bitarr![Msb0, u128; 0; 70];
+		bitarr![Lsb0, u128; 1; 70];
+
+		#[cfg(target_pointer_width = "64")]
+		{
+			bitarr![Msb0, u64; 0; 70];
+			bitarr![Lsb0, u64; 1; 70];
+		}
+	}
+
 	#[test]
 	#[cfg(feature = "alloc")]
 	fn compile_bitvec_macros() {
@@ -242,6 +397,8 @@ mod tests {
 		bitvec![Lsb0, u16; 0, 1];
 		bitvec![Msb0, u32; 0, 1];
 		bitvec![Lsb0, u32; 0, 1];
+		bitvec![Msb0, u128; 0, 1];
+		bitvec![Lsb0, u128; 0, 1];
 
 		#[cfg(target_pointer_width = "64")]
 		{
@@ -258,6 +415,8 @@ mod tests {
 		bitvec![Lsb0, u16; 1; 70];
 		bitvec![Msb0, u32; 0; 70];
 		bitvec![Lsb0, u32; 1; 70];
+		bitvec![Msb0, u128; 0; 70];
+		bitvec![Lsb0, u128; 1; 70];
 
 		#[cfg(target_pointer_width = "64")]
 		{
@@ -278,6 +437,8 @@ mod tests {
 		bitbox![Lsb0, u16; 0, 1];
 		bitbox![Msb0, u32; 0, 1];
 		bitbox![Lsb0, u32; 0, 1];
+		bitbox![Msb0, u128; 0, 1];
+		bitbox![Lsb0, u128; 0, 1];
 
 		#[cfg(target_pointer_width = "64")]
 		{
@@ -294,6 +455,8 @@ mod tests {
 		bitbox![Lsb0, u16; 1; 70];
 		bitbox![Msb0, u32; 0; 70];
 		bitbox![Lsb0, u32; 1; 70];
+		bitbox![Msb0, u128; 0; 70];
+		bitbox![Lsb0, u128; 1; 70];
 
 		#[cfg(target_pointer_width = "64")]
 		{
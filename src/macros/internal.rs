@@ -34,6 +34,24 @@ macro_rules! __bits_from_slice {
 	};
 }
 
+/// Ensures that the ordering tokens map to a known ordering type path.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitarr_from_array {
+	(Local, $array:expr) => {
+		$crate::array::BitArray::<$crate::order::Local, _>::new($array)
+	};
+	(Lsb0, $array:expr) => {
+		$crate::array::BitArray::<$crate::order::Lsb0, _>::new($array)
+	};
+	(Msb0, $array:expr) => {
+		$crate::array::BitArray::<$crate::order::Msb0, _>::new($array)
+	};
+	($order:tt, $array:expr) => {
+		$crate::array::BitArray::<$order, _>::new($array)
+	};
+}
+
 /** Accumulates a stream of bit expressions into a compacted array of elements.
 
 This macro constructs a well-ordered `[T; N]` array expression usable in `const`
@@ -71,7 +89,11 @@ macro_rules! __bits_store_array {
 			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 16
 			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 32
 			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 48
-			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0  // 64
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 64
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 80
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 96
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // 112
+			0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0  // 128
 		);
 	};
 
@@ -168,6 +190,48 @@ macro_rules! __bits_store_array {
 			$($($t)*)?
 		)
 	};
+	(
+		$order:tt, u128 $(@ $usz:ident)?, [$($w:tt)*];
+		$a0:tt, $b0:tt, $c0:tt, $d0:tt, $e0:tt, $f0:tt, $g0:tt, $h0:tt,
+		$a1:tt, $b1:tt, $c1:tt, $d1:tt, $e1:tt, $f1:tt, $g1:tt, $h1:tt,
+		$a2:tt, $b2:tt, $c2:tt, $d2:tt, $e2:tt, $f2:tt, $g2:tt, $h2:tt,
+		$a3:tt, $b3:tt, $c3:tt, $d3:tt, $e3:tt, $f3:tt, $g3:tt, $h3:tt,
+		$a4:tt, $b4:tt, $c4:tt, $d4:tt, $e4:tt, $f4:tt, $g4:tt, $h4:tt,
+		$a5:tt, $b5:tt, $c5:tt, $d5:tt, $e5:tt, $f5:tt, $g5:tt, $h5:tt,
+		$a6:tt, $b6:tt, $c6:tt, $d6:tt, $e6:tt, $f6:tt, $g6:tt, $h6:tt,
+		$a7:tt, $b7:tt, $c7:tt, $d7:tt, $e7:tt, $f7:tt, $g7:tt, $h7:tt,
+		$a8:tt, $b8:tt, $c8:tt, $d8:tt, $e8:tt, $f8:tt, $g8:tt, $h8:tt,
+		$a9:tt, $b9:tt, $c9:tt, $d9:tt, $e9:tt, $f9:tt, $g9:tt, $h9:tt,
+		$a10:tt, $b10:tt, $c10:tt, $d10:tt, $e10:tt, $f10:tt, $g10:tt, $h10:tt,
+		$a11:tt, $b11:tt, $c11:tt, $d11:tt, $e11:tt, $f11:tt, $g11:tt, $h11:tt,
+		$a12:tt, $b12:tt, $c12:tt, $d12:tt, $e12:tt, $f12:tt, $g12:tt, $h12:tt,
+		$a13:tt, $b13:tt, $c13:tt, $d13:tt, $e13:tt, $f13:tt, $g13:tt, $h13:tt,
+		$a14:tt, $b14:tt, $c14:tt, $d14:tt, $e14:tt, $f14:tt, $g14:tt, $h14:tt,
+		$a15:tt, $b15:tt, $c15:tt, $d15:tt, $e15:tt, $f15:tt, $g15:tt, $h15:tt
+		$(, $($t:tt)*)?
+	) => {
+		$crate::__bits_store_array!(
+			$order, u128 $(@ $usz)?, [$($w)* (
+				$a0, $b0, $c0, $d0, $e0, $f0, $g0, $h0,
+				$a1, $b1, $c1, $d1, $e1, $f1, $g1, $h1,
+				$a2, $b2, $c2, $d2, $e2, $f2, $g2, $h2,
+				$a3, $b3, $c3, $d3, $e3, $f3, $g3, $h3,
+				$a4, $b4, $c4, $d4, $e4, $f4, $g4, $h4,
+				$a5, $b5, $c5, $d5, $e5, $f5, $g5, $h5,
+				$a6, $b6, $c6, $d6, $e6, $f6, $g6, $h6,
+				$a7, $b7, $c7, $d7, $e7, $f7, $g7, $h7,
+				$a8, $b8, $c8, $d8, $e8, $f8, $g8, $h8,
+				$a9, $b9, $c9, $d9, $e9, $f9, $g9, $h9,
+				$a10, $b10, $c10, $d10, $e10, $f10, $g10, $h10,
+				$a11, $b11, $c11, $d11, $e11, $f11, $g11, $h11,
+				$a12, $b12, $c12, $d12, $e12, $f12, $g12, $h12,
+				$a13, $b13, $c13, $d13, $e13, $f13, $g13, $h13,
+				$a14, $b14, $c14, $d14, $e14, $f14, $g14, $h14,
+				$a15, $b15, $c15, $d15, $e15, $f15, $g15, $h15
+			)];
+			$($($t)*)?
+		)
+	};
 }
 
 /// Counts the number of repetitions inside a `$()*` sequence.
@@ -410,6 +474,15 @@ macro_rules! __ty_from_bytes {
 	(Local, u64, [$($byte:expr),*]) => {
 		$crate::macros::internal::u64_from_ne_bytes([$($byte),*])
 	};
+	(Msb0, u128, [$($byte:expr),*]) => {
+		$crate::macros::internal::u128_from_be_bytes([$($byte),*])
+	};
+	(Lsb0, u128, [$($byte:expr),*]) => {
+		$crate::macros::internal::u128_from_le_bytes([$($byte),*])
+	};
+	(Local, u128, [$($byte:expr),*]) => {
+		$crate::macros::internal::u128_from_ne_bytes([$($byte),*])
+	};
 	(Msb0, usize, [$($byte:expr),*]) => {
 		$crate::macros::internal::usize_from_be_bytes([$($byte),*])
 	};
@@ -521,6 +594,30 @@ pub const fn u64_from_le_bytes(bytes: [u8; 8]) -> u64 {
 		| u32_from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64
 }
 
+#[doc(hidden)]
+pub const fn u128_from_be_bytes(bytes: [u8; 16]) -> u128 {
+	(u64_from_be_bytes([
+		bytes[0], bytes[1], bytes[2], bytes[3],
+		bytes[4], bytes[5], bytes[6], bytes[7],
+	]) as u128) << 64
+		| u64_from_be_bytes([
+			bytes[8], bytes[9], bytes[10], bytes[11],
+			bytes[12], bytes[13], bytes[14], bytes[15],
+		]) as u128
+}
+
+#[doc(hidden)]
+pub const fn u128_from_le_bytes(bytes: [u8; 16]) -> u128 {
+	(u64_from_le_bytes([
+		bytes[8], bytes[9], bytes[10], bytes[11],
+		bytes[12], bytes[13], bytes[14], bytes[15],
+	]) as u128) << 64
+		| u64_from_le_bytes([
+			bytes[0], bytes[1], bytes[2], bytes[3],
+			bytes[4], bytes[5], bytes[6], bytes[7],
+		]) as u128
+}
+
 #[doc(hidden)]
 #[cfg(target_pointer_width = "32")]
 pub const fn usize_from_be_bytes(bytes: [u8; 4]) -> usize {
@@ -561,6 +658,10 @@ pub use u32_from_be_bytes as u32_from_ne_bytes;
 #[cfg(target_endian = "big")]
 pub use u64_from_be_bytes as u64_from_ne_bytes;
 
+#[doc(hidden)]
+#[cfg(target_endian = "big")]
+pub use u128_from_be_bytes as u128_from_ne_bytes;
+
 #[doc(hidden)]
 #[cfg(target_endian = "big")]
 pub use usize_from_be_bytes as usize_from_ne_bytes;
@@ -581,6 +682,10 @@ pub use u32_from_le_bytes as u32_from_ne_bytes;
 #[cfg(target_endian = "little")]
 pub use u64_from_le_bytes as u64_from_ne_bytes;
 
+#[doc(hidden)]
+#[cfg(target_endian = "little")]
+pub use u128_from_le_bytes as u128_from_ne_bytes;
+
 #[doc(hidden)]
 #[cfg(target_endian = "little")]
 pub use usize_from_le_bytes as usize_from_ne_bytes;
@@ -15,12 +15,15 @@ Contiguity is not required.
 !*/
 
 use crate::{
+	fields,
 	indices::{
 		BitIdx,
 		BitMask,
 		BitPos,
+		BitTail,
 		Indexable,
 	},
+	slice::BitSlice,
 	store::BitStore,
 };
 
@@ -149,6 +152,192 @@ pub trait BitOrder {
 		);
 		unsafe { BitMask::new_unchecked(T::from(1) << *place) }
 	}
+
+	/// Translate a semantic range into a multiple-bit electrical mask.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation combines the one-hot masks produced by
+	/// `Self::mask` for each index in the range. This is correct for every
+	/// ordering, but visits each bit in turn. Orderings whose electrical
+	/// positions are contiguous should provide a shift-based implementation.
+	///
+	/// # Parameters
+	///
+	/// - `from`: The first semantic index included in the mask.
+	/// - `upto`: The first semantic index *not* included in the mask. This may
+	///   be `T::BITS`, to mark the range as reaching the end of the element.
+	///
+	/// # Returns
+	///
+	/// An element value with the bits at each index in `from .. upto` set high,
+	/// and all other bits set low. If `upto` is not greater than `from`, this
+	/// is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type for which the mask will be calculated.
+	fn mask_range<T>(from: BitIdx<T>, upto: BitTail<T>) -> T
+	where T: BitStore {
+		(*from .. *upto).fold(T::FALSE, |mask, n| {
+			mask | *Self::mask::<T>(n.idx())
+		})
+	}
+
+	/// Finds the semantic index of the first set bit in an element.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation tests each index, starting at zero, until it
+	/// finds one whose bit is set. Orderings that correspond to a hardware bit
+	/// scan should use that instruction instead.
+	///
+	/// # Parameters
+	///
+	/// - `elt`: An element value. All of its bits are considered live; callers
+	///   must clear any bits that they do not wish to search.
+	///
+	/// # Returns
+	///
+	/// The lowest semantic index whose bit is set in `elt`, or `None` if `elt`
+	/// is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type being searched.
+	fn first_one<T>(elt: T) -> Option<BitIdx<T>>
+	where T: BitStore {
+		(0 .. T::BITS)
+			.map(Indexable::idx)
+			.find(|&n| elt & *Self::mask::<T>(n) != T::FALSE)
+	}
+
+	/// Finds the semantic index of the last set bit in an element.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation tests each index, starting at `T::MASK` and
+	/// moving downwards, until it finds one whose bit is set. Orderings that
+	/// correspond to a hardware bit scan should use that instruction instead.
+	///
+	/// # Parameters
+	///
+	/// - `elt`: An element value. All of its bits are considered live; callers
+	///   must clear any bits that they do not wish to search.
+	///
+	/// # Returns
+	///
+	/// The highest semantic index whose bit is set in `elt`, or `None` if `elt`
+	/// is zero.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type being searched.
+	fn last_one<T>(elt: T) -> Option<BitIdx<T>>
+	where T: BitStore {
+		(0 .. T::BITS)
+			.rev()
+			.map(Indexable::idx)
+			.find(|&n| elt & *Self::mask::<T>(n) != T::FALSE)
+	}
+
+	/// Moves every bit in an element by the same semantic distance.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation tests each index and sets its destination
+	/// index in turn. Orderings whose electrical positions are contiguous
+	/// should use a single shift instruction instead.
+	///
+	/// # Parameters
+	///
+	/// - `elt`: An element value.
+	/// - `from`: A semantic index in `elt`.
+	/// - `to`: The semantic index to which the bit at `from` is moved.
+	///
+	/// # Returns
+	///
+	/// An element value in which the bit at each index `n + to - from` is the
+	/// bit at index `n` in `elt`. Bits moved past either edge of the element
+	/// are discarded, and indices which receive no bit are set low.
+	///
+	/// # Type Parameters
+	///
+	/// - `T`: The storage type being moved.
+	fn realign<T>(elt: T, from: BitIdx<T>, to: BitIdx<T>) -> T
+	where T: BitStore {
+		let (from, to) = (*from, *to);
+		(0 .. T::BITS)
+			.filter(|&n| n + to >= from && n + to - from < T::BITS)
+			.filter(|&n| elt & *Self::mask::<T>(n.idx()) != T::FALSE)
+			.fold(T::FALSE, |out, n| {
+				out | *Self::mask::<T>((n + to - from).idx())
+			})
+	}
+
+	/// Loads a little-endian field from a slice of this ordering.
+	///
+	/// This is not part of the public API. It is the implementation of
+	/// `BitField::load_le` for `BitSlice<Self, T>`, which has already checked
+	/// that `bits` is not empty and not wider than `U`.
+	///
+	/// The default implementation gathers the live bits of each element through
+	/// the mask produced by `Self::mask_range`, and is correct for every
+	/// ordering. `Lsb0` and `Msb0` replace it with one shift and mask per
+	/// element.
+	#[doc(hidden)]
+	fn field_load_le<T, U>(bits: &BitSlice<Self, T>) -> U
+	where
+		Self: Sized,
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::load_le_any(bits)
+	}
+
+	/// Loads a big-endian field from a slice of this ordering.
+	///
+	/// This is the counterpart of `field_load_le` for `BitField::load_be`.
+	#[doc(hidden)]
+	fn field_load_be<T, U>(bits: &BitSlice<Self, T>) -> U
+	where
+		Self: Sized,
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::load_be_any(bits)
+	}
+
+	/// Stores a little-endian field into a slice of this ordering.
+	///
+	/// This is the counterpart of `field_load_le` for `BitField::store_le`,
+	/// which has already cleared every bit of `value` above `bits.len()`.
+	#[doc(hidden)]
+	fn field_store_le<T, U>(bits: &mut BitSlice<Self, T>, value: U)
+	where
+		Self: Sized,
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::store_le_any(bits, value)
+	}
+
+	/// Stores a big-endian field into a slice of this ordering.
+	///
+	/// This is the counterpart of `field_store_le` for `BitField::store_be`.
+	#[doc(hidden)]
+	fn field_store_be<T, U>(bits: &mut BitSlice<Self, T>, value: U)
+	where
+		Self: Sized,
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::store_be_any(bits, value)
+	}
 }
 
 impl BitOrder for Msb0 {
@@ -170,6 +359,80 @@ impl BitOrder for Msb0 {
 		//  only a single right-shift.
 		unsafe { BitMask::new_unchecked((T::from(1) << T::MASK) >> *place) }
 	}
+
+	fn mask_range<T>(from: BitIdx<T>, upto: BitTail<T>) -> T
+	where T: BitStore {
+		//  Clear the `from` most significant bits, then keep only the `upto`
+		//  most significant bits. A full-width shift is not permitted, so the
+		//  element-wide tail is handled separately.
+		let keep = if *upto >= T::BITS {
+			T::TRUE
+		}
+		else {
+			!(T::TRUE >> *upto)
+		};
+		(T::TRUE >> *from) & keep
+	}
+
+	fn first_one<T>(elt: T) -> Option<BitIdx<T>>
+	where T: BitStore {
+		if elt == T::FALSE {
+			return None;
+		}
+		//  The first index is the most significant bit.
+		Some((elt.leading_zeros() as u8).idx())
+	}
+
+	fn last_one<T>(elt: T) -> Option<BitIdx<T>>
+	where T: BitStore {
+		if elt == T::FALSE {
+			return None;
+		}
+		Some((T::MASK - elt.trailing_zeros() as u8).idx())
+	}
+
+	fn realign<T>(elt: T, from: BitIdx<T>, to: BitIdx<T>) -> T
+	where T: BitStore {
+		//  Higher indices are less significant, so moving up is a right-shift.
+		if *to >= *from {
+			elt >> (*to - *from)
+		}
+		else {
+			elt << (*from - *to)
+		}
+	}
+
+	fn field_load_le<T, U>(bits: &BitSlice<Self, T>) -> U
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::load_le_msb0(bits)
+	}
+
+	fn field_load_be<T, U>(bits: &BitSlice<Self, T>) -> U
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::load_be_msb0(bits)
+	}
+
+	fn field_store_le<T, U>(bits: &mut BitSlice<Self, T>, value: U)
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::store_le_msb0(bits, value)
+	}
+
+	fn field_store_be<T, U>(bits: &mut BitSlice<Self, T>, value: U)
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::store_be_msb0(bits, value)
+	}
 }
 
 impl BitOrder for Lsb0 {
@@ -188,6 +451,79 @@ impl BitOrder for Lsb0 {
 		//  Set the LSbit, then shift it up.
 		unsafe { BitMask::new_unchecked(T::from(1) << *place) }
 	}
+
+	fn mask_range<T>(from: BitIdx<T>, upto: BitTail<T>) -> T
+	where T: BitStore {
+		//  Clear the `from` least significant bits, then keep only the `upto`
+		//  least significant bits.
+		let keep = if *upto >= T::BITS {
+			T::TRUE
+		}
+		else {
+			!(T::TRUE << *upto)
+		};
+		(T::TRUE << *from) & keep
+	}
+
+	fn first_one<T>(elt: T) -> Option<BitIdx<T>>
+	where T: BitStore {
+		if elt == T::FALSE {
+			return None;
+		}
+		//  The first index is the least significant bit.
+		Some((elt.trailing_zeros() as u8).idx())
+	}
+
+	fn last_one<T>(elt: T) -> Option<BitIdx<T>>
+	where T: BitStore {
+		if elt == T::FALSE {
+			return None;
+		}
+		Some((T::MASK - elt.leading_zeros() as u8).idx())
+	}
+
+	fn realign<T>(elt: T, from: BitIdx<T>, to: BitIdx<T>) -> T
+	where T: BitStore {
+		//  Higher indices are more significant, so moving up is a left-shift.
+		if *to >= *from {
+			elt << (*to - *from)
+		}
+		else {
+			elt >> (*from - *to)
+		}
+	}
+
+	fn field_load_le<T, U>(bits: &BitSlice<Self, T>) -> U
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::load_le_lsb0(bits)
+	}
+
+	fn field_load_be<T, U>(bits: &BitSlice<Self, T>) -> U
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::load_be_lsb0(bits)
+	}
+
+	fn field_store_le<T, U>(bits: &mut BitSlice<Self, T>, value: U)
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::store_le_lsb0(bits, value)
+	}
+
+	fn field_store_be<T, U>(bits: &mut BitSlice<Self, T>, value: U)
+	where
+		T: BitStore,
+		U: BitStore,
+	{
+		fields::store_be_lsb0(bits, value)
+	}
 }
 
 /** A default bit ordering.
@@ -209,11 +545,104 @@ pub type Local = Lsb0;
 #[cfg(not(any(target_endian = "big", target_endian = "little")))]
 compile_fail!("This architecture is currently not supported. File an issue at https://github.com/myrrlyn/bitvec");
 
+/** A bijective ordering with no contiguous structure, for tests.
+
+It rotates each element by half its width, so that a run of indices may wrap
+around the element’s edges. Tests use it to check the default implementations
+of the provided `BitOrder` methods, and the behavior of the rest of the crate
+under orderings other than `Lsb0` and `Msb0`.
+**/
+#[cfg(test)]
+pub(crate) struct Swizzle;
+
+#[cfg(test)]
+impl BitOrder for Swizzle {
+	const TYPENAME: &'static str = "Swizzle";
+
+	fn at<T>(place: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		//  Swap the halves of the element.
+		((*place + (T::BITS >> 1)) & T::MASK).pos()
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity)] // Permit large test functions
 mod tests {
 	use super::*;
 
+	fn check_ranges<O, T>()
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		for from in 0 .. T::BITS {
+			for upto in from ..= T::BITS {
+				let expected = (from .. upto).fold(T::FALSE, |mask, n| {
+					mask | *O::mask::<T>(n.idx())
+				});
+				assert_eq!(
+					O::mask_range::<T>(from.idx(), upto.tail()),
+					expected,
+					"{} {} .. {}",
+					O::TYPENAME,
+					from,
+					upto,
+				);
+			}
+			let elt = *O::mask::<T>(from.idx());
+			assert_eq!(O::first_one(elt), Some(from.idx()));
+			assert_eq!(O::last_one(elt), Some(from.idx()));
+			let below = O::mask_range::<T>(0u8.idx(), (from + 1).tail());
+			assert_eq!(O::first_one(below), Some(0u8.idx()));
+			assert_eq!(O::last_one(below), Some(from.idx()));
+		}
+		assert!(O::first_one(T::FALSE).is_none());
+		assert!(O::last_one(T::FALSE).is_none());
+
+		for from in 0 .. T::BITS {
+			for to in 0 .. T::BITS {
+				for n in 0 .. T::BITS {
+					let elt = *O::mask::<T>(n.idx());
+					let expected = if n + to >= from && n + to - from < T::BITS {
+						*O::mask::<T>((n + to - from).idx())
+					}
+					else {
+						T::FALSE
+					};
+					assert_eq!(
+						O::realign(elt, from.idx(), to.idx()),
+						expected,
+						"{} {}: {} -> {}",
+						O::TYPENAME,
+						n,
+						from,
+						to,
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn ranges() {
+		check_ranges::<Msb0, u8>();
+		check_ranges::<Msb0, u16>();
+		check_ranges::<Msb0, u32>();
+		check_ranges::<Lsb0, u8>();
+		check_ranges::<Lsb0, u16>();
+		check_ranges::<Lsb0, u32>();
+		check_ranges::<Swizzle, u8>();
+		check_ranges::<Swizzle, u32>();
+
+		#[cfg(target_pointer_width = "64")]
+		{
+			check_ranges::<Msb0, u64>();
+			check_ranges::<Lsb0, u64>();
+			check_ranges::<Swizzle, u64>();
+		}
+	}
+
 	#[test]
 	fn be_u8_range() {
 		assert_eq!(Msb0::at::<u8>(0u8.idx()), 7u8.pos());
@@ -24,7 +24,10 @@ use core::{
 		Formatter,
 	},
 	marker::PhantomData,
-	mem::size_of,
+	mem::{
+		align_of,
+		size_of,
+	},
 	ptr::NonNull,
 	slice,
 };
@@ -179,8 +182,8 @@ struct BitPtr {
   size_t ptr_head : __builtin_ctzll(alignof(T));
   size_t ptr_data : sizeof(uintptr_t) * 8 - __builtin_ctzll(alignof(T));
 
-  size_t len_head : 3;
-  size_t len_bits : sizeof(size_t) * 8 - 3;
+  size_t len_head : 3 + __builtin_ctzll(sizeof(T) / alignof(T));
+  size_t len_bits : sizeof(size_t) * 8 - len_head;
 };
 ```
 
@@ -205,11 +208,11 @@ address of a `T` to the address of a `u8`. It is stored in the high bits of the
 
 The memory representation stores a counter of the live bits contained in the
 slice, starting at the head index. This counter occupies all but the lowest
-three bits of the `len` structural field.
+`len_head` bits of the `len` structural field.
 
 ## Head Bit Index
 
-For any fundamental type `T`, `core::mem::align_of::<T>().trailing_zeros() + 3`
+For any fundamental type `T`, `core::mem::size_of::<T>().trailing_zeros() + 3`
 bits are required to count the bit positions inside it.
 
 |Type |Alignment|Trailing Zeros|Count Bits|
@@ -219,9 +222,14 @@ bits are required to count the bit positions inside it.
 |`u32`|        4|             2|         5|
 |`u64`|        8|             3|         6|
 
-The head bit counter is split such that its bottom three bits are stored in the
-low bits of the `len` field and the remaining high bits are stored in the low
-bits of `ptr`.
+The head bit counter is split such that its high bits are stored in the low bits
+of `ptr` that alignment leaves free, and its remaining low bits are stored in
+the low bits of the `len` field. For the types above, this places the bottom
+three bits of the counter in `len`.
+
+`u128` needs seven bits to count its positions, but is aligned to eight bytes,
+rather than sixteen, on some targets. There, the `len` field holds the bottom
+four bits of the counter, and the bit counter loses one bit of range.
 
 The counter is a value in the range `0 .. (1 << Count)` that serves as a cursor
 into the zeroth storage element to find the first live bit.
@@ -304,11 +312,13 @@ where T: BitStore
 	/// The number of low bits in `self.len` that are the low bits of the head
 	/// `BitIdx` cursor.
 	///
-	/// This is always `3`, until Rust tries to target a machine whose bytes are
-	/// not eight bits wide.
-	pub const LEN_HEAD_BITS: usize = 3;
+	/// This is `3` for every type whose alignment is its size. Types that are
+	/// less aligned than they are wide, such as `u128` on targets that align it
+	/// to eight bytes, have fewer spare bits in `self.ptr`, and keep the rest
+	/// of the head cursor here.
+	pub const LEN_HEAD_BITS: usize = T::INDX as usize - Self::PTR_HEAD_BITS;
 	/// Marks the bits of `self.len` that are the `head` section.
-	pub const LEN_HEAD_MASK: usize = 0b0111;
+	pub const LEN_HEAD_MASK: usize = (1 << Self::LEN_HEAD_BITS) - 1;
 	/// The inclusive maximum bit index.
 	pub const MAX_BITS: usize = !0 >> Self::LEN_HEAD_BITS;
 	/// The inclusive maximum number of elements that can be stored in a
@@ -318,9 +328,11 @@ where T: BitStore
 	pub const PTR_DATA_MASK: usize = !Self::PTR_HEAD_MASK;
 	/// The number of low bits in `self.ptr` that are the high bits of the head
 	/// `BitIdx` cursor.
-	pub const PTR_HEAD_BITS: usize = T::INDX as usize - Self::LEN_HEAD_BITS;
+	///
+	/// These are the bits that are always zero in a well-aligned `*const T`.
+	pub const PTR_HEAD_BITS: usize = align_of::<T>().trailing_zeros() as usize;
 	/// Marks the bits of `self.ptr` that are the `head` section.
-	pub const PTR_HEAD_MASK: usize = T::MASK as usize >> Self::LEN_HEAD_BITS;
+	pub const PTR_HEAD_MASK: usize = (1 << Self::PTR_HEAD_BITS) - 1;
 
 	/// Produces an empty-slice representation.
 	///
@@ -484,17 +496,21 @@ where T: BitStore
 	{
 		let (data, head) = (data.into(), *head as usize);
 
-		let ptr_data = data.u() & Self::PTR_DATA_MASK;
 		let ptr_head = head >> Self::LEN_HEAD_BITS;
 
 		let len_head = head & Self::LEN_HEAD_MASK;
 		let len_bits = bits << Self::LEN_HEAD_BITS;
 
-		let ptr: Pointer<u8> = (ptr_data | ptr_head).into();
+		//  Move the address with pointer arithmetic, rather than rebuilding it
+		//  from an integer, so that it keeps the provenance of `data`.
+		let ptr = data.w() as *mut u8;
+		let ptr = ptr
+			.wrapping_sub(data.u() & Self::PTR_HEAD_MASK)
+			.wrapping_add(ptr_head);
 
 		Self {
 			_ty: PhantomData,
-			ptr: NonNull::new_unchecked(ptr.w()),
+			ptr: NonNull::new_unchecked(ptr),
 			len: len_bits | len_head,
 		}
 	}
@@ -516,7 +532,12 @@ where T: BitStore
 	/// regime in order for the caller to dereference it.
 	#[inline]
 	pub(crate) fn pointer(&self) -> Pointer<T> {
-		(self.ptr.as_ptr() as usize & Self::PTR_DATA_MASK).into()
+		let ptr = self.ptr.as_ptr();
+		//  Clear the head bits by offsetting the pointer, not by masking its
+		//  address, so that the element pointer keeps the provenance of the
+		//  reference it came from.
+		let head = ptr as usize & Self::PTR_HEAD_MASK;
+		(ptr.wrapping_sub(head) as *mut T).into()
 	}
 
 	/// Overwrites the data pointer with a new address. This method does not
@@ -534,14 +555,17 @@ where T: BitStore
 	#[inline]
 	#[cfg(feature = "alloc")]
 	pub(crate) unsafe fn set_pointer(&mut self, ptr: impl Into<Pointer<T>>) {
-		let mut data = ptr.into();
+		let data = ptr.into();
 		if data.r().is_null() {
 			*self = Self::empty();
 			return;
 		}
-		data.u &= Self::PTR_DATA_MASK;
-		data.u |= self.ptr.as_ptr() as usize & Self::PTR_HEAD_MASK;
-		self.ptr = NonNull::new_unchecked(data.w() as *mut u8);
+		let head = self.ptr.as_ptr() as usize & Self::PTR_HEAD_MASK;
+		let ptr = data.w() as *mut u8;
+		self.ptr = NonNull::new_unchecked(
+			ptr.wrapping_sub(data.u() & Self::PTR_HEAD_MASK)
+				.wrapping_add(head),
+		);
 	}
 
 	/// Extracts the element cursor of the head bit.
@@ -565,13 +589,15 @@ where T: BitStore
 	#[cfg(feature = "alloc")]
 	pub unsafe fn set_head(&mut self, head: BitIdx<T>) {
 		let head = *head as usize;
-		let mut ptr = self.ptr.as_ptr() as usize;
+		let ptr = self.ptr.as_ptr();
 
-		//  Erase the head section of the pointer value.
-		ptr &= !Self::PTR_HEAD_MASK;
-		//  Write the pointer section of the head value into the head section.
-		ptr |= head >> Self::LEN_HEAD_BITS;
-		self.ptr = NonNull::new_unchecked(ptr as *mut u8);
+		//  Erase the head section of the pointer value, and write the pointer
+		//  section of the head value into it, by offsetting the pointer.
+		let old = ptr as usize & Self::PTR_HEAD_MASK;
+		let ptr = ptr
+			.wrapping_sub(old)
+			.wrapping_add(head >> Self::LEN_HEAD_BITS);
+		self.ptr = NonNull::new_unchecked(ptr);
 
 		//  Erase the head section of the length value.
 		self.len &= !Self::LEN_HEAD_MASK;
@@ -948,6 +974,33 @@ mod tests {
 		assert_eq!(BitPtr::<u64>::PTR_HEAD_MASK, 7);
 	}
 
+	#[test]
+	fn associated_consts_u128() {
+		let ptr_bits = align_of::<u128>().trailing_zeros() as usize;
+		assert_eq!(BitPtr::<u128>::PTR_HEAD_BITS, ptr_bits);
+		assert_eq!(BitPtr::<u128>::LEN_HEAD_BITS, 7 - ptr_bits);
+
+		assert_eq!(BitPtr::<u128>::PTR_DATA_MASK, !0 << ptr_bits);
+		assert_eq!(BitPtr::<u128>::LEN_HEAD_MASK, 0x7F >> ptr_bits);
+	}
+
+	#[test]
+	fn u128_heads() {
+		let data = [0u128; 3];
+		for head in 0 .. 128 {
+			let ptr = &data as *const u128;
+			let mut bp = BitPtr::<u128>::new(ptr, head.idx(), 200);
+			assert_eq!(bp.pointer().r(), ptr);
+			assert_eq!(*bp.head(), head);
+			assert_eq!(bp.len(), 200);
+			unsafe {
+				bp.set_len(57);
+			}
+			assert_eq!(*bp.head(), head);
+			assert_eq!(bp.len(), 57);
+		}
+	}
+
 	#[test]
 	fn ctors() {
 		let data: [u32; 4] = [0; 4];
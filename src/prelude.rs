@@ -5,8 +5,14 @@ This collects the general public API into a single spot for inclusion, as
 !*/
 
 pub use crate::{
+	array::BitArray,
+	bitarr,
 	bits,
-	fields::BitField,
+	fields::{
+		BitField,
+		BitFieldSigned,
+		BitFieldWide,
+	},
 	order::{
 		BitOrder,
 		Local,
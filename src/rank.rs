@@ -0,0 +1,408 @@
+/*! Succinct rank and select queries
+
+`BitSlice::count_ones` answers how many bits are set in an entire slice, but
+compressed indices and succinct data structures repeatedly ask two narrower
+questions:
+
+- *rank*: how many bits of a given value precede some index?
+- *select*: at which index does the `k`th bit of a given value live?
+
+Answering these by counting or iterating from the front of the slice on every
+query takes time linear in the slice length. This module provides a
+[`RankSelect`] directory, which precomputes running population counts over a
+borrowed `BitSlice` so that rank queries take constant time and select queries
+take logarithmic time.
+
+[`RankSelect`]: struct.RankSelect.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	order::{
+		BitOrder,
+		Local,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use alloc::vec::Vec;
+
+use core::cmp;
+
+/// The number of bits summarized by each entry in the block directory.
+const BLOCK_BITS: usize = 256;
+
+/// The number of blocks summarized by each entry in the superblock directory.
+const BLOCKS_PER_SUPER: usize = 16;
+
+/// The number of bits summarized by each entry in the superblock directory.
+const SUPER_BITS: usize = BLOCK_BITS * BLOCKS_PER_SUPER;
+
+/** A rank/select directory over a borrowed `BitSlice`.
+
+The directory divides the slice into *superblocks* of 4096 bits, and each
+superblock into *blocks* of 256 bits. It records, for every superblock, the
+number of set bits in the slice before it, and for every block, the number of
+set bits between the start of its superblock and the start of the block.
+
+# Queries
+
+- [`rank1`] and [`rank0`] sum one superblock entry, one block entry, and the
+  population count of at most 255 bits of the slice. They run in constant time.
+- [`select1`] and [`select0`] binary search the superblock directory, scan at
+  most 16 block entries, and then search at most 256 bits of the slice. They
+  run in time logarithmic in the slice length.
+
+# Memory Overhead
+
+The superblock directory holds one `usize` per 4096 bits, and the block
+directory holds one `u16` per 256 bits. On targets with a 64-bit `usize`, the
+directory uses `64 / 4096 + 16 / 256`, or about 7.8%, of the size of the slice
+it describes; with a 32-bit `usize`, it uses about 7.0%. The slice itself is
+borrowed, not copied.
+
+# Type Parameters
+
+- `O`: The `BitOrder` of the described slice.
+- `T`: The `BitStore` element type of the described slice.
+
+# Lifetimes
+
+- `'a`: The lifetime of the borrowed slice.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::rank::RankSelect;
+
+let data = [0b0100_1001u8, 0b1000_0000];
+let rs = RankSelect::new(data.bits::<Msb0>());
+
+assert_eq!(rs.rank1(4), 1);
+assert_eq!(rs.rank0(4), 3);
+assert_eq!(rs.select1(2), Some(7));
+assert_eq!(rs.select0(0), Some(0));
+assert!(rs.select1(4).is_none());
+```
+
+[`rank0`]: #method.rank0
+[`rank1`]: #method.rank1
+[`select0`]: #method.select0
+[`select1`]: #method.select1
+**/
+#[derive(Clone, Debug)]
+pub struct RankSelect<'a, O = Local, T = usize>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The slice described by the directory.
+	bits: &'a BitSlice<O, T>,
+	/// The number of set bits before each superblock.
+	supers: Vec<usize>,
+	/// The number of set bits before each block, within its superblock.
+	blocks: Vec<u16>,
+	/// The number of set bits in the entire slice.
+	ones: usize,
+}
+
+impl<'a, O, T> RankSelect<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// Builds a rank/select directory over a slice.
+	///
+	/// This counts every bit in `bits` once, a block at a time.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice that the directory will describe.
+	///
+	/// # Returns
+	///
+	/// A directory able to answer rank and select queries about `bits`.
+	pub fn new(bits: &'a BitSlice<O, T>) -> Self {
+		let mut supers = Vec::with_capacity(bits.len() / SUPER_BITS + 1);
+		let mut blocks = Vec::with_capacity(bits.len() / BLOCK_BITS + 1);
+		let mut ones = 0usize;
+		let mut base = 0usize;
+
+		for (n, block) in bits.chunks(BLOCK_BITS).enumerate() {
+			if n % BLOCKS_PER_SUPER == 0 {
+				supers.push(ones);
+				base = ones;
+			}
+			//  A superblock holds at most 4096 bits, so this cannot truncate.
+			blocks.push((ones - base) as u16);
+			ones += block.count_ones();
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+		}
+	}
+
+	/// Accesses the slice described by the directory.
+	#[inline]
+	pub fn as_bitslice(&self) -> &'a BitSlice<O, T> {
+		self.bits
+	}
+
+	/// Counts how many bits in the described slice are set high.
+	///
+	/// This is precomputed, and does not inspect the slice.
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Counts how many bits in the described slice are set low.
+	///
+	/// This is precomputed, and does not inspect the slice.
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.bits.len() - self.ones
+	}
+
+	/// Counts the set bits before an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: An index in the domain `0 ..= self.as_bitslice().len()`. The
+	///   bit at `index` itself is not counted.
+	///
+	/// # Returns
+	///
+	/// The number of `1` bits in `self.as_bitslice()[.. index]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the length of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let data = [!0u16; 300];
+	/// let rs = RankSelect::new(data.bits::<Lsb0>());
+	/// assert_eq!(rs.rank1(0), 0);
+	/// assert_eq!(rs.rank1(4500), 4500);
+	/// assert_eq!(rs.rank1(4800), 4800);
+	/// ```
+	pub fn rank1(&self, index: usize) -> usize {
+		let len = self.bits.len();
+		assert!(index <= len, "Index out of range: {} > {}", index, len);
+		let block = index / BLOCK_BITS;
+		//  Only an index at the end of a block-aligned slice has no block.
+		if block == self.blocks.len() {
+			return self.ones;
+		}
+		let start = block * BLOCK_BITS;
+		self.supers[block / BLOCKS_PER_SUPER]
+			+ self.blocks[block] as usize
+			+ unsafe { self.bits.get_unchecked(start .. index) }.count_ones()
+	}
+
+	/// Counts the unset bits before an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: An index in the domain `0 ..= self.as_bitslice().len()`. The
+	///   bit at `index` itself is not counted.
+	///
+	/// # Returns
+	///
+	/// The number of `0` bits in `self.as_bitslice()[.. index]`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the length of the slice.
+	#[inline]
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of a set bit by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of set bits that precede the sought bit. `0`
+	///   selects the first set bit in the slice.
+	///
+	/// # Returns
+	///
+	/// The index of the `1` bit which has `rank` other `1` bits before it, or
+	/// `None` if the slice does not have that many set bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let mut data = [0u32; 300];
+	/// data[200] = 1;
+	/// data[250] = 1;
+	/// let rs = RankSelect::new(data.bits::<Lsb0>());
+	/// assert_eq!(rs.select1(0), Some(6400));
+	/// assert_eq!(rs.select1(1), Some(8000));
+	/// assert!(rs.select1(2).is_none());
+	/// ```
+	#[inline]
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		self.select(rank, true)
+	}
+
+	/// Finds the index of an unset bit by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of unset bits that precede the sought bit. `0`
+	///   selects the first unset bit in the slice.
+	///
+	/// # Returns
+	///
+	/// The index of the `0` bit which has `rank` other `0` bits before it, or
+	/// `None` if the slice does not have that many unset bits.
+	#[inline]
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		self.select(rank, false)
+	}
+
+	/// Finds the index of the bit with a given value and rank.
+	///
+	/// Zero counts are not stored; they are derived from the one counts and
+	/// the fixed number of bits before each superblock and block.
+	fn select(&self, mut rank: usize, value: bool) -> Option<usize> {
+		let total = if value {
+			self.count_ones()
+		}
+		else {
+			self.count_zeros()
+		};
+		if rank >= total {
+			return None;
+		}
+
+		let supers = |s: usize| {
+			let ones = self.supers[s];
+			if value { ones } else { s * SUPER_BITS - ones }
+		};
+		//  Find the last superblock that does not begin after the sought bit.
+		let (mut lo, mut hi) = (0, self.supers.len());
+		while hi - lo > 1 {
+			let mid = lo + (hi - lo) / 2;
+			if supers(mid) <= rank {
+				lo = mid;
+			}
+			else {
+				hi = mid;
+			}
+		}
+		rank -= supers(lo);
+
+		let first = lo * BLOCKS_PER_SUPER;
+		let last = cmp::min(first + BLOCKS_PER_SUPER, self.blocks.len());
+		let blocks = |b: usize| {
+			let ones = self.blocks[b] as usize;
+			if value {
+				ones
+			}
+			else {
+				(b - first) * BLOCK_BITS - ones
+			}
+		};
+		let mut block = first;
+		while block + 1 < last && blocks(block + 1) <= rank {
+			block += 1;
+		}
+		rank -= blocks(block);
+
+		let start = block * BLOCK_BITS;
+		let end = cmp::min(start + BLOCK_BITS, self.bits.len());
+		let bits = unsafe { self.bits.get_unchecked(start .. end) };
+		if value {
+			bits.iter_ones().nth(rank)
+		}
+		else {
+			bits.iter_zeros().nth(rank)
+		}
+		.map(|n| start + n)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	fn check<O, T>(bits: &BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let rs = RankSelect::new(bits);
+		assert_eq!(rs.count_ones(), bits.count_ones());
+		assert_eq!(rs.count_zeros(), bits.count_zeros());
+
+		for index in 0 ..= bits.len() {
+			let ones = bits[.. index].count_ones();
+			assert_eq!(rs.rank1(index), ones, "rank1({})", index);
+			assert_eq!(rs.rank0(index), index - ones, "rank0({})", index);
+		}
+		for (rank, index) in bits.iter_ones().enumerate() {
+			assert_eq!(rs.select1(rank), Some(index), "select1({})", rank);
+		}
+		for (rank, index) in bits.iter_zeros().enumerate() {
+			assert_eq!(rs.select0(rank), Some(index), "select0({})", rank);
+		}
+		assert!(rs.select1(bits.count_ones()).is_none());
+		assert!(rs.select0(bits.count_zeros()).is_none());
+	}
+
+	#[test]
+	fn empty() {
+		check(BitSlice::<Local, usize>::empty());
+	}
+
+	#[test]
+	fn patterns() {
+		//  A simple LCG provides an irregular, but reproducible, pattern.
+		let mut state = 0x2545_F491u32;
+		let mut words = [0u32; 300];
+		for word in words.iter_mut() {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			*word = state;
+		}
+		check(words.bits::<Lsb0>());
+		check(&words.bits::<Msb0>()[3 ..]);
+		check(&words.bits::<Lsb0>()[.. 9000]);
+
+		let sparse = {
+			let mut sparse = [0u8; 1200];
+			sparse[1] = 0x10;
+			sparse[700] = 0x81;
+			sparse[1199] = 0x01;
+			sparse
+		};
+		check(sparse.bits::<Msb0>());
+		check(&sparse.bits::<Lsb0>()[5 .. 9597]);
+
+		let dense = [!0u16; 600];
+		check(dense.bits::<Msb0>());
+		check(&dense.bits::<Lsb0>()[1 ..]);
+	}
+}
@@ -0,0 +1,778 @@
+/*! Compressed bitmaps
+
+A `BitVec` used as a set of `u32` values costs one bit for every value up to
+the greatest member, no matter how few members there are. This module provides
+[`RoaringBitmap`], which partitions the `u32` space into chunks of 65,536
+values and chooses a representation for each chunk by how full it is:
+
+- chunks with no members are not stored at all;
+- chunks with at most 4,096 members store them as a sorted array of `u16`
+  offsets, at two bytes per member;
+- fuller chunks store a `BitBox<Lsb0, usize>` of 65,536 bits, at a fixed eight
+  kibibytes.
+
+The crossover is the point at which the two representations are the same size,
+so a chunk never uses more than eight kibibytes. Chunks convert between the two
+representations automatically as members are inserted and removed.
+
+[`RoaringBitmap`]: struct.RoaringBitmap.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	boxed::BitBox,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::{
+		elts,
+		BitStore,
+	},
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		FromIterator,
+		FusedIterator,
+	},
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+		Sub,
+	},
+	slice,
+};
+
+/// The number of values covered by each chunk.
+const CHUNK_BITS: usize = 1 << 16;
+
+/// The number of `usize` elements in a dense chunk.
+const CHUNK_ELTS: usize = elts::<usize>(CHUNK_BITS);
+
+/// The greatest number of members that a sparse chunk may hold.
+const SPARSE_MAX: usize = 4096;
+
+/** A compressed set of `u32` values.
+
+See the [module documentation] for the storage layout.
+
+# Examples
+
+```rust
+use bitvec::roaring::RoaringBitmap;
+
+let mut ids = RoaringBitmap::new();
+ids.insert(7);
+ids.insert(1 << 20);
+ids.extend(100_000 .. 110_000);
+
+assert_eq!(ids.len(), 10_002);
+assert!(ids.contains(105_000));
+assert_eq!(ids.max(), Some(1 << 20));
+
+let evens: RoaringBitmap = (0 .. 1 << 17).step_by(2).collect();
+assert_eq!(ids.intersection(&evens).len(), 5_000);
+```
+
+[module documentation]: index.html
+**/
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct RoaringBitmap {
+	/// The non-empty chunks, in ascending order of key.
+	chunks: Vec<Chunk>,
+}
+
+/// The members of a bitmap that share their high sixteen bits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Chunk {
+	/// The high sixteen bits shared by every member of the chunk.
+	key: u16,
+	/// The low sixteen bits of every member of the chunk.
+	store: Container,
+}
+
+/// The storage for a single chunk.
+///
+/// A container is `Sparse` exactly when it holds no more than `SPARSE_MAX`
+/// members. Every operation that changes a container restores this, so that
+/// equal sets always have equal representations.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Container {
+	/// The members, in ascending order.
+	Sparse(Vec<u16>),
+	/// A bit for every value in the chunk, and the number that are set.
+	Dense {
+		bits: BitBox<Lsb0, usize>,
+		ones: usize,
+	},
+}
+
+/// A set operation, as applied to both individual members and whole words.
+#[derive(Clone, Copy, Debug)]
+enum Op {
+	Or,
+	And,
+	AndNot,
+	Xor,
+}
+
+impl Op {
+	/// Decides whether a value is in the result, by its presence in each
+	/// operand.
+	fn keep(self, a: bool, b: bool) -> bool {
+		match self {
+			Op::Or => a | b,
+			Op::And => a & b,
+			Op::AndNot => a & !b,
+			Op::Xor => a ^ b,
+		}
+	}
+
+	/// Applies the operation to a word of values at once.
+	fn word(self, a: usize, b: usize) -> usize {
+		match self {
+			Op::Or => a | b,
+			Op::And => a & b,
+			Op::AndNot => a & !b,
+			Op::Xor => a ^ b,
+		}
+	}
+}
+
+impl Container {
+	/// Builds a container from members in ascending order, choosing its
+	/// representation by their count.
+	fn from_sparse(vals: Vec<u16>) -> Option<Self> {
+		match vals.len() {
+			0 => None,
+			n if n <= SPARSE_MAX => Some(Container::Sparse(vals)),
+			ones => Some(Container::Dense {
+				bits: Self::dense_from(&vals),
+				ones,
+			}),
+		}
+	}
+
+	/// Builds a container from a full chunk of bits, choosing its
+	/// representation by the number set.
+	fn from_dense(bits: BitBox<Lsb0, usize>) -> Option<Self> {
+		let ones = bits.count_ones();
+		if ones > SPARSE_MAX {
+			Some(Container::Dense { bits, ones })
+		}
+		else {
+			Self::from_sparse(bits.iter_ones().map(|n| n as u16).collect())
+		}
+	}
+
+	fn empty_dense() -> BitBox<Lsb0, usize> {
+		BitBox::from_slice(&[0usize; CHUNK_ELTS][..])
+	}
+
+	/// Sets the bit for each of a list of members in an empty chunk.
+	fn dense_from(vals: &[u16]) -> BitBox<Lsb0, usize> {
+		let mut bits = Self::empty_dense();
+		for &v in vals {
+			bits.set(v as usize, true);
+		}
+		bits
+	}
+
+	fn len(&self) -> usize {
+		match self {
+			Container::Sparse(vals) => vals.len(),
+			Container::Dense { ones, .. } => *ones,
+		}
+	}
+
+	fn contains(&self, val: u16) -> bool {
+		match self {
+			Container::Sparse(vals) => vals.binary_search(&val).is_ok(),
+			Container::Dense { bits, .. } => bits[val as usize],
+		}
+	}
+
+	fn insert(&mut self, val: u16) -> bool {
+		match self {
+			Container::Sparse(vals) => {
+				let idx = match vals.binary_search(&val) {
+					Ok(_) => return false,
+					Err(idx) => idx,
+				};
+				vals.insert(idx, val);
+				if vals.len() > SPARSE_MAX {
+					let ones = vals.len();
+					let bits = Self::dense_from(vals);
+					*self = Container::Dense { bits, ones };
+				}
+				true
+			},
+			Container::Dense { bits, ones } => {
+				if bits[val as usize] {
+					return false;
+				}
+				bits.set(val as usize, true);
+				*ones += 1;
+				true
+			},
+		}
+	}
+
+	/// Removes a member, returning whether it was present. The container may
+	/// be left empty; the caller must discard it.
+	fn remove(&mut self, val: u16) -> bool {
+		match self {
+			Container::Sparse(vals) => match vals.binary_search(&val) {
+				Ok(idx) => {
+					vals.remove(idx);
+					true
+				},
+				Err(_) => false,
+			},
+			Container::Dense { bits, ones } => {
+				if !bits[val as usize] {
+					return false;
+				}
+				bits.set(val as usize, false);
+				*ones -= 1;
+				if *ones <= SPARSE_MAX {
+					let vals = bits.iter_ones().map(|n| n as u16).collect();
+					*self = Container::Sparse(vals);
+				}
+				true
+			},
+		}
+	}
+
+	fn iter(&self) -> ContainerIter {
+		match self {
+			Container::Sparse(vals) => ContainerIter::Sparse(vals.iter()),
+			Container::Dense { bits, .. } => {
+				ContainerIter::Dense(bits.iter_ones())
+			},
+		}
+	}
+
+	/// Copies the container into a full chunk of bits.
+	fn to_dense(&self) -> BitBox<Lsb0, usize> {
+		match self {
+			Container::Sparse(vals) => Self::dense_from(vals),
+			Container::Dense { bits, .. } => bits.clone(),
+		}
+	}
+
+	/// Applies a set operation to two containers.
+	///
+	/// Two sparse containers are merged member by member. Otherwise, both are
+	/// expanded to full chunks and combined a word at a time.
+	fn combine(&self, other: &Self, op: Op) -> Option<Self> {
+		if let (Container::Sparse(a), Container::Sparse(b)) = (self, other) {
+			let mut out = Vec::new();
+			let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+			loop {
+				let (val, in_a, in_b) = match (a.peek(), b.peek()) {
+					(None, None) => break,
+					(Some(&&x), Some(&&y)) => match x.cmp(&y) {
+						Ordering::Less => (x, true, false),
+						Ordering::Greater => (y, false, true),
+						Ordering::Equal => (x, true, true),
+					},
+					(Some(&&x), None) => (x, true, false),
+					(None, Some(&&y)) => (y, false, true),
+				};
+				if in_a {
+					a.next();
+				}
+				if in_b {
+					b.next();
+				}
+				if op.keep(in_a, in_b) {
+					out.push(val);
+				}
+			}
+			return Self::from_sparse(out);
+		}
+		let mut bits = self.to_dense();
+		let rhs = other.to_dense();
+		for (a, b) in bits.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+			*a = op.word(*a, *b);
+		}
+		Self::from_dense(bits)
+	}
+}
+
+/// Iterator over the members of one container.
+#[derive(Clone, Debug)]
+enum ContainerIter<'a> {
+	Sparse(slice::Iter<'a, u16>),
+	Dense(IterOnes<'a, Lsb0, usize>),
+}
+
+impl Iterator for ContainerIter<'_> {
+	type Item = u16;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			ContainerIter::Sparse(iter) => iter.next().copied(),
+			ContainerIter::Dense(iter) => iter.next().map(|n| n as u16),
+		}
+	}
+}
+
+impl RoaringBitmap {
+	/// Constructs an empty bitmap.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Builds a bitmap of the indices of the set bits in a slice.
+	///
+	/// Each 65,536-bit window of the slice is counted first, so that its
+	/// chunk is built directly in the correct representation.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: Any bit slice.
+	///
+	/// # Returns
+	///
+	/// A bitmap whose members are the indices of the `1` bits in `bits`.
+	///
+	/// # Panics
+	///
+	/// This panics if `bits` has a set bit whose index does not fit in a
+	/// `u32`.
+	pub fn from_bitslice<O, T>(bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		if let Some(last) = bits.last_one() {
+			assert!(
+				(last as u64) >> 32 == 0,
+				"Index {} is out of range for a u32 bitmap",
+				last,
+			);
+		}
+		let mut chunks = Vec::new();
+		for (key, window) in bits.chunks(CHUNK_BITS).enumerate() {
+			let store = if window.count_ones() > SPARSE_MAX {
+				let mut dense = Container::empty_dense();
+				dense[.. window.len()].clone_from_slice(window);
+				Container::from_dense(dense)
+			}
+			else {
+				Container::from_sparse(
+					window.iter_ones().map(|n| n as u16).collect(),
+				)
+			};
+			if let Some(store) = store {
+				chunks.push(Chunk {
+					key: key as u16,
+					store,
+				});
+			}
+		}
+		Self { chunks }
+	}
+
+	/// Expands the bitmap into a bit vector.
+	///
+	/// # Returns
+	///
+	/// A bit vector one bit longer than the greatest member, in which the bit
+	/// at each member index is set.
+	pub fn to_bitvec<O, T>(&self) -> BitVec<O, T>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let len = self.max().map_or(0, |n| n as usize + 1);
+		let mut out = BitVec::repeat(false, len);
+		for n in self.iter() {
+			out.set(n as usize, true);
+		}
+		out
+	}
+
+	/// Counts the members of the bitmap.
+	pub fn len(&self) -> u64 {
+		self.chunks.iter().map(|c| c.store.len() as u64).sum()
+	}
+
+	/// Tests if the bitmap has no members.
+	pub fn is_empty(&self) -> bool {
+		self.chunks.is_empty()
+	}
+
+	/// Removes all members from the bitmap.
+	pub fn clear(&mut self) {
+		self.chunks.clear();
+	}
+
+	/// Tests if a value is a member of the bitmap.
+	pub fn contains(&self, value: u32) -> bool {
+		let (key, low) = split(value);
+		self.find(key)
+			.map(|idx| self.chunks[idx].store.contains(low))
+			.unwrap_or(false)
+	}
+
+	/// Adds a value to the bitmap.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was not already a member.
+	pub fn insert(&mut self, value: u32) -> bool {
+		let (key, low) = split(value);
+		match self.find(key) {
+			Ok(idx) => self.chunks[idx].store.insert(low),
+			Err(idx) => {
+				self.chunks.insert(idx, Chunk {
+					key,
+					store: Container::Sparse(vec![low]),
+				});
+				true
+			},
+		}
+	}
+
+	/// Removes a value from the bitmap.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was a member.
+	pub fn remove(&mut self, value: u32) -> bool {
+		let (key, low) = split(value);
+		let idx = match self.find(key) {
+			Ok(idx) => idx,
+			Err(_) => return false,
+		};
+		let found = self.chunks[idx].store.remove(low);
+		if self.chunks[idx].store.len() == 0 {
+			self.chunks.remove(idx);
+		}
+		found
+	}
+
+	/// Finds the least member of the bitmap.
+	pub fn min(&self) -> Option<u32> {
+		self.iter().next()
+	}
+
+	/// Finds the greatest member of the bitmap.
+	pub fn max(&self) -> Option<u32> {
+		let chunk = self.chunks.last()?;
+		let low = match &chunk.store {
+			Container::Sparse(vals) => *vals.last()?,
+			Container::Dense { bits, .. } => bits.last_one()? as u16,
+		};
+		Some(join(chunk.key, low))
+	}
+
+	/// Iterates over the members of the bitmap, in ascending order.
+	pub fn iter(&self) -> Iter {
+		Iter {
+			chunks: self.chunks.iter(),
+			current: None,
+		}
+	}
+
+	/// Builds the bitmap of values in either bitmap.
+	pub fn union(&self, other: &Self) -> Self {
+		self.combine(other, Op::Or)
+	}
+
+	/// Builds the bitmap of values in both bitmaps.
+	pub fn intersection(&self, other: &Self) -> Self {
+		self.combine(other, Op::And)
+	}
+
+	/// Builds the bitmap of values in `self` but not in `other`.
+	pub fn difference(&self, other: &Self) -> Self {
+		self.combine(other, Op::AndNot)
+	}
+
+	/// Builds the bitmap of values in exactly one of the bitmaps.
+	pub fn symmetric_difference(&self, other: &Self) -> Self {
+		self.combine(other, Op::Xor)
+	}
+
+	/// Tests if every member of `self` is also a member of `other`.
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.difference(other).is_empty()
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).is_empty()
+	}
+
+	/// Locates the chunk with a given key.
+	fn find(&self, key: u16) -> Result<usize, usize> {
+		self.chunks.binary_search_by_key(&key, |c| c.key)
+	}
+
+	/// Applies a set operation to every pair of chunks with matching keys.
+	///
+	/// A chunk present in only one operand is kept or dropped whole, as the
+	/// operation dictates.
+	fn combine(&self, other: &Self, op: Op) -> Self {
+		let mut chunks = Vec::new();
+		let (mut a, mut b) = (self.chunks.iter(), other.chunks.iter());
+		let (mut x, mut y) = (a.next(), b.next());
+		loop {
+			match (x, y) {
+				(None, None) => break,
+				(Some(c), Some(d)) if c.key == d.key => {
+					if let Some(store) = c.store.combine(&d.store, op) {
+						chunks.push(Chunk { key: c.key, store });
+					}
+					x = a.next();
+					y = b.next();
+				},
+				(Some(c), Some(d)) if c.key < d.key => {
+					if op.keep(true, false) {
+						chunks.push(c.clone());
+					}
+					x = a.next();
+				},
+				(Some(c), None) => {
+					if op.keep(true, false) {
+						chunks.push(c.clone());
+					}
+					x = a.next();
+				},
+				(_, Some(d)) => {
+					if op.keep(false, true) {
+						chunks.push(d.clone());
+					}
+					y = b.next();
+				},
+			}
+		}
+		Self { chunks }
+	}
+}
+
+/// Splits a value into its chunk key and its offset within the chunk.
+fn split(value: u32) -> (u16, u16) {
+	((value >> 16) as u16, value as u16)
+}
+
+/// Rejoins a chunk key and an offset into a value.
+fn join(key: u16, low: u16) -> u32 {
+	u32::from(key) << 16 | u32::from(low)
+}
+
+impl Debug for RoaringBitmap {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl Extend<u32> for RoaringBitmap {
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item = u32> {
+		for n in src {
+			self.insert(n);
+		}
+	}
+}
+
+impl FromIterator<u32> for RoaringBitmap {
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item = u32> {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<O, T> From<&BitSlice<O, T>> for RoaringBitmap
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(src: &BitSlice<O, T>) -> Self {
+		Self::from_bitslice(src)
+	}
+}
+
+impl<'a> IntoIterator for &'a RoaringBitmap {
+	type IntoIter = Iter<'a>;
+	type Item = u32;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl BitAnd for &RoaringBitmap {
+	type Output = RoaringBitmap;
+
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.intersection(rhs)
+	}
+}
+
+impl BitOr for &RoaringBitmap {
+	type Output = RoaringBitmap;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.union(rhs)
+	}
+}
+
+impl BitXor for &RoaringBitmap {
+	type Output = RoaringBitmap;
+
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		self.symmetric_difference(rhs)
+	}
+}
+
+impl Sub for &RoaringBitmap {
+	type Output = RoaringBitmap;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.difference(rhs)
+	}
+}
+
+/** Iterator over the members of a `RoaringBitmap`, in ascending order.
+
+This struct is created by the [`iter`] method on [`RoaringBitmap`].
+
+[`RoaringBitmap`]: struct.RoaringBitmap.html
+[`iter`]: struct.RoaringBitmap.html#method.iter
+**/
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+	chunks: slice::Iter<'a, Chunk>,
+	current: Option<(u16, ContainerIter<'a>)>,
+}
+
+impl Iterator for Iter<'_> {
+	type Item = u32;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((key, iter)) = &mut self.current {
+				if let Some(low) = iter.next() {
+					return Some(join(*key, low));
+				}
+			}
+			let chunk = self.chunks.next()?;
+			self.current = Some((chunk.key, chunk.store.iter()));
+		}
+	}
+}
+
+impl FusedIterator for Iter<'_> {
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::order::Msb0;
+
+	fn reps(map: &RoaringBitmap) -> Vec<bool> {
+		map.chunks
+			.iter()
+			.map(|c| match c.store {
+				Container::Sparse(_) => false,
+				Container::Dense { .. } => true,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn conversion() {
+		let mut map = RoaringBitmap::new();
+		map.extend(0 .. SPARSE_MAX as u32);
+		assert_eq!(reps(&map), &[false]);
+		map.insert(SPARSE_MAX as u32);
+		assert_eq!(reps(&map), &[true]);
+		assert_eq!(map.len(), SPARSE_MAX as u64 + 1);
+		map.remove(0);
+		assert_eq!(reps(&map), &[false]);
+		for n in 1 ..= SPARSE_MAX as u32 {
+			assert!(map.remove(n));
+		}
+		assert!(map.is_empty());
+		assert!(map.chunks.is_empty());
+	}
+
+	#[test]
+	fn members() {
+		let vals = [0u32, 5, 65_535, 65_536, 1 << 20, !0];
+		let map: RoaringBitmap = vals.iter().copied().collect();
+		assert_eq!(map.iter().collect::<Vec<_>>(), &vals);
+		assert_eq!(map.min(), Some(0));
+		assert_eq!(map.max(), Some(!0));
+		assert!(map.contains(65_536));
+		assert!(!map.contains(65_537));
+	}
+
+	#[test]
+	fn algebra() {
+		//  Each operand has a sparse chunk, a dense chunk, and chunks that the
+		//  other does not, so that every container pairing is combined.
+		let a: RoaringBitmap = (0 .. 70_000)
+			.step_by(3)
+			.chain(200_000 .. 200_010)
+			.collect();
+		let b: RoaringBitmap = (0 .. 140_000)
+			.step_by(5)
+			.chain(300_000 .. 300_010)
+			.collect();
+		let model = |keep: fn(bool, bool) -> bool| -> Vec<u32> {
+			(0 .. 400_000)
+				.filter(|&n| keep(a.contains(n), b.contains(n)))
+				.collect()
+		};
+		let check = |map: RoaringBitmap, keep: fn(bool, bool) -> bool| {
+			assert_eq!(map.iter().collect::<Vec<_>>(), model(keep));
+			//  Rebuilding from the members must give the same layout.
+			let canon: RoaringBitmap = map.iter().collect();
+			assert_eq!(map, canon);
+		};
+		check(&a | &b, |x, y| x | y);
+		check(&a & &b, |x, y| x & y);
+		check(&a - &b, |x, y| x & !y);
+		check(&b - &a, |x, y| y & !x);
+		check(&a ^ &b, |x, y| x ^ y);
+		assert!((&a & &b).is_subset(&a));
+		assert!((&a - &b).is_disjoint(&b));
+	}
+
+	#[test]
+	fn bitvecs() {
+		let mut bv = BitVec::<Msb0, u8>::repeat(false, 140_000);
+		bv[10 .. 20].set_all(true);
+		bv[65_536 .. 75_536].set_all(true);
+		bv.set(139_999, true);
+		let map = RoaringBitmap::from_bitslice(&bv);
+		assert_eq!(reps(&map), &[false, true, false]);
+		assert_eq!(map.len(), 10_011);
+		assert_eq!(map.to_bitvec::<Msb0, u8>(), bv);
+		assert_eq!(RoaringBitmap::from(&bv[.. 0]), RoaringBitmap::new());
+	}
+}
@@ -2,9 +2,14 @@
 
 This module implements the Serde traits for the `bitvec` types, as possible.
 
-Without an allocator, only `BitSlice` exists, and can only implement
-`Serialize`. With an allocator, the `BitBox` and `BitVec` types exist, and are
-able to implement `Deserialize` as well.
+Without an allocator, only `BitSlice` and `BitArray` exist. `BitSlice` can only
+implement `Serialize`; `BitArray` owns its storage by value, and so implements
+`Deserialize` as well. With an allocator, the `BitBox` and `BitVec` types exist,
+and are able to implement `Deserialize` too.
+
+All of these types use the same serialized form, so a `BitArray` can be read
+from the serialization of any `BitSlice` that begins at the front of its first
+element and spans every bit of its storage.
 !*/
 
 #![cfg(all(feature = "serde"))]
@@ -12,8 +17,12 @@ able to implement `Deserialize` as well.
 use std::prelude::v1::*;
 
 use crate::{
+	array::BitArray,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
 	store::BitStore,
 };
 
@@ -24,15 +33,18 @@ use crate::{
 	vec::BitVec,
 };
 
-#[cfg(feature = "alloc")]
 use core::{
-	cmp,
-	convert::TryInto,
 	fmt::{
 		self,
 		Formatter,
 	},
 	marker::PhantomData,
+};
+
+#[cfg(feature = "alloc")]
+use core::{
+	cmp,
+	convert::TryInto,
 	mem,
 };
 
@@ -44,7 +56,6 @@ use serde::{
 	Serialize,
 };
 
-#[cfg(feature = "alloc")]
 use serde::{
 	de::{
 		self,
@@ -58,6 +69,120 @@ use serde::{
 	Deserialize,
 };
 
+/// A Serde visitor to pull `BitArray` data out of a serialized stream
+#[derive(Clone, Copy, Default, Debug)]
+pub struct BitArrayVisitor<'de, O, V>
+where
+	O: BitOrder,
+	V: AsBits + Deserialize<'de>,
+{
+	_order: PhantomData<O>,
+	_storage: PhantomData<&'de V>,
+}
+
+impl<'de, O, V> BitArrayVisitor<'de, O, V>
+where
+	O: BitOrder,
+	V: AsBits + Deserialize<'de>,
+{
+	fn new() -> Self {
+		BitArrayVisitor {
+			_order: PhantomData,
+			_storage: PhantomData,
+		}
+	}
+
+	/// Checks that the serialized head and length describe a region that
+	/// fills the array, then wraps the data.
+	fn assemble<E>(
+		&self,
+		head: u8,
+		bits: usize,
+		data: V,
+	) -> Result<BitArray<O, V>, E>
+	where
+		E: Error,
+	{
+		if head != 0 {
+			return Err(E::invalid_value(
+				Unexpected::Unsigned(u64::from(head)),
+				&"a head index of 0",
+			));
+		}
+		let arr = BitArray::<O, V>::new(data);
+		if bits != arr.len() {
+			return Err(E::invalid_length(bits, self));
+		}
+		Ok(arr)
+	}
+}
+
+impl<'de, O, V> Visitor<'de> for BitArrayVisitor<'de, O, V>
+where
+	O: BitOrder,
+	V: AsBits + Deserialize<'de>,
+{
+	type Value = BitArray<O, V>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("A BitSet data series spanning a whole array")
+	}
+
+	/// Visit a sequence of anonymous data elements. These must be in the order
+	/// `u8`, `usize`, `[T; N]`.
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		let head: u8 = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let bits: usize = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		let data: V = seq
+			.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+		self.assemble(head, bits, data)
+	}
+
+	/// Visit a map of named data elements. These may be in any order, and must
+	/// be the pairs `head: u8`, `bits: usize`, and `data: [T; N]`.
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where A: MapAccess<'de> {
+		let mut head: Option<u8> = None;
+		let mut bits: Option<usize> = None;
+		let mut data: Option<V> = None;
+
+		while let Some(key) = map.next_key()? {
+			match key {
+				"head" => {
+					if head.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("head"));
+					}
+				},
+				"bits" => {
+					if bits.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("bits"));
+					}
+				},
+				"data" => {
+					if data.replace(map.next_value()?).is_some() {
+						return Err(de::Error::duplicate_field("data"));
+					}
+				},
+				f => {
+					return Err(de::Error::unknown_field(f, &[
+						"head", "bits", "data",
+					]));
+				},
+			}
+		}
+		let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
+		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
+		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+		self.assemble(head, bits, data)
+	}
+}
+
 /// A Serde visitor to pull `BitBox` data out of a serialized stream
 #[cfg(feature = "alloc")]
 #[derive(Clone, Copy, Default, Debug)]
@@ -175,6 +300,21 @@ where
 	}
 }
 
+impl<'de, O, V> Deserialize<'de> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: 'de + AsBits + Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct(
+			"BitSet",
+			&["head", "bits", "data"],
+			BitArrayVisitor::new(),
+		)
+	}
+}
+
 #[cfg(feature = "alloc")]
 impl<'de, O, T> Deserialize<'de> for BitBox<O, T>
 where
@@ -222,6 +362,19 @@ where
 	}
 }
 
+impl<O, V> Serialize for BitArray<O, V>
+where
+	O: BitOrder,
+	V: AsBits,
+	V::Store: Serialize,
+	<V::Store as BitStore>::Access: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		BitSlice::serialize(self.as_bitslice(), serializer)
+	}
+}
+
 #[cfg(feature = "alloc")]
 impl<O, T> Serialize for BitBox<O, T>
 where
@@ -251,9 +404,8 @@ where
 #[cfg(test)]
 mod tests {
 	use crate::prelude::*;
-	#[cfg(feature = "alloc")]
-	use serde_test::assert_de_tokens;
 	use serde_test::{
+		assert_de_tokens,
 		assert_ser_tokens,
 		Token,
 	};
@@ -316,6 +468,13 @@ mod tests {
 		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, !0]);
 	}
 
+	#[test]
+	fn array() {
+		let arr = bitarr![Msb0, u8; 0, 1, 1, 0, 1, 0];
+		assert_ser_tokens(&arr, bvtok![s 1, 0, 8, U8, 0b0110_1000]);
+		assert_de_tokens(&arr, bvtok![d 1, 0, 8, U8, 0b0110_1000]);
+	}
+
 	#[cfg(feature = "alloc")]
 	#[test]
 	fn deser() {
@@ -0,0 +1,818 @@
+/*! `BitSet` structure
+
+This module holds a set of small unsigned integers, stored as a `BitVec` in
+which each set bit marks its index as a member of the set. The vector grows as
+larger members are inserted, and membership tests and updates are single-bit
+operations.
+
+The set algebra operations are available in two forms: lazy iterators, which
+walk both sets in ascending order without allocating, and in-place methods,
+which apply the operation to whole elements of the receiving set at once.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	order::{
+		BitOrder,
+		Local,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::{
+		elts,
+		BitStore,
+	},
+	vec::BitVec,
+};
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::{
+		FromIterator,
+		FusedIterator,
+		Peekable,
+	},
+};
+
+/** A set of `usize` values, stored as a bit vector.
+
+The member `n` is present when bit `n` of the underlying `BitVec` is set. The
+vector is never shorter than one past the greatest member, and grows on
+insertion as needed; it may be longer, with all trailing bits unset.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of bits within the underlying storage elements.
+- `T: BitStore`: The storage element type of the underlying vector.
+
+# Examples
+
+```rust
+use bitvec::set::BitSet;
+
+let mut primes: BitSet = BitSet::new();
+for n in &[2, 3, 5, 7, 11] {
+    primes.insert(*n);
+}
+let odds: BitSet = (1 .. 12).step_by(2).collect();
+
+assert!(primes.contains(7));
+assert_eq!(primes.len(), 5);
+assert_eq!(
+    primes.intersection(&odds).collect::<Vec<_>>(),
+    &[3, 5, 7, 11],
+);
+```
+**/
+#[derive(Clone, Default)]
+pub struct BitSet<O = Local, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bits: BitVec<O, T>,
+}
+
+impl<O, T> BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs an empty set.
+	///
+	/// This does not allocate until a member is inserted.
+	pub fn new() -> Self {
+		Self { bits: BitVec::new() }
+	}
+
+	/// Constructs an empty set with room for members up to a given value.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: One past the greatest member that the set can hold
+	///   before it must reallocate.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::with_capacity(capacity),
+		}
+	}
+
+	/// Wraps a bit vector as a set of the indices of its set bits.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: A bit vector, whose set bits become the members of the set.
+	///   If it does not begin at the front of its first element, it is moved
+	///   there, so that the in-place set operations can work on its elements.
+	pub fn from_bitvec(mut bits: BitVec<O, T>) -> Self {
+		bits.force_align();
+		Self { bits }
+	}
+
+	/// Unwraps the set into its underlying bit vector.
+	///
+	/// # Returns
+	///
+	/// A bit vector in which the bit at each member index is set.
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		self.bits
+	}
+
+	/// Views the set as a bit slice, in which each member index is set.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bits.as_bitslice()
+	}
+
+	/// Counts the members of the set.
+	///
+	/// This counts the set bits of the underlying vector, an element at a
+	/// time.
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Removes all members from the set, keeping its allocation.
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Tests if a value is a member of the set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: Any value. Values past the end of the underlying vector are
+	///   not members.
+	///
+	/// # Returns
+	///
+	/// Whether `value` is in the set.
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).copied().unwrap_or(false)
+	}
+
+	/// Adds a value to the set.
+	///
+	/// The underlying vector grows if `value` is past its end.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to insert.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was not already in the set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set: BitSet = BitSet::new();
+	/// assert!(set.insert(100));
+	/// assert!(!set.insert(100));
+	/// assert!(set.contains(100));
+	/// assert!(!set.contains(99));
+	/// ```
+	pub fn insert(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			self.bits.resize(value + 1, false);
+		}
+		let old = self.bits[value];
+		self.bits.set(value, true);
+		!old
+	}
+
+	/// Removes a value from the set.
+	///
+	/// This never shrinks the underlying vector; see [`shrink_to_fit`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The value to remove.
+	///
+	/// # Returns
+	///
+	/// `true` if `value` was in the set.
+	///
+	/// [`shrink_to_fit`]: #method.shrink_to_fit
+	pub fn remove(&mut self, value: usize) -> bool {
+		if !self.contains(value) {
+			return false;
+		}
+		self.bits.set(value, false);
+		true
+	}
+
+	/// Finds the least member of the set.
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Finds the greatest member of the set.
+	pub fn last(&self) -> Option<usize> {
+		self.bits.last_one()
+	}
+
+	/// Truncates the underlying vector to one past the greatest member, and
+	/// releases any excess allocation.
+	pub fn shrink_to_fit(&mut self) {
+		let len = self.last().map_or(0, |n| n + 1);
+		self.bits.truncate(len);
+		self.bits.shrink_to_fit();
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	pub fn iter(&self) -> IterOnes<O, T> {
+		self.bits.iter_ones()
+	}
+
+	/// Iterates over the values in either set, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a: BitSet = [1, 2, 3].iter().copied().collect();
+	/// let b: BitSet = [3, 4].iter().copied().collect();
+	/// assert_eq!(a.union(&b).collect::<Vec<_>>(), &[1, 2, 3, 4]);
+	/// ```
+	pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, O, T> {
+		Union {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+
+	/// Iterates over the values in both sets, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a: BitSet = [1, 2, 3].iter().copied().collect();
+	/// let b: BitSet = [3, 4].iter().copied().collect();
+	/// assert_eq!(a.intersection(&b).collect::<Vec<_>>(), &[3]);
+	/// ```
+	pub fn intersection<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> Intersection<'a, O, T>
+	{
+		Intersection {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+
+	/// Iterates over the values in `self` but not in `other`, in ascending
+	/// order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a: BitSet = [1, 2, 3].iter().copied().collect();
+	/// let b: BitSet = [3, 4].iter().copied().collect();
+	/// assert_eq!(a.difference(&b).collect::<Vec<_>>(), &[1, 2]);
+	/// ```
+	pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, O, T> {
+		Difference {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+
+	/// Iterates over the values in exactly one of the sets, in ascending
+	/// order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a: BitSet = [1, 2, 3].iter().copied().collect();
+	/// let b: BitSet = [3, 4].iter().copied().collect();
+	/// assert_eq!(
+	///     a.symmetric_difference(&b).collect::<Vec<_>>(),
+	///     &[1, 2, 4],
+	/// );
+	/// ```
+	pub fn symmetric_difference<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> SymmetricDifference<'a, O, T>
+	{
+		SymmetricDifference {
+			a: self.iter().peekable(),
+			b: other.iter().peekable(),
+		}
+	}
+
+	/// Adds every member of `other` to `self`.
+	///
+	/// This operates on whole elements, and grows `self` to the length of
+	/// `other` if it is shorter.
+	pub fn union_with(&mut self, other: &Self) {
+		let olen = other.bits.len();
+		if olen > self.bits.len() {
+			self.bits.resize(olen, false);
+		}
+		self.merge_elements(other, |a, b| a | b);
+	}
+
+	/// Removes every member of `self` that is not in `other`.
+	///
+	/// This operates on whole elements.
+	pub fn intersect_with(&mut self, other: &Self) {
+		let len = cmp::min(self.bits.len(), other.bits.len());
+		self.merge_elements(other, |a, b| a & b);
+		self.bits[len ..].set_all(false);
+	}
+
+	/// Removes every member of `other` from `self`.
+	///
+	/// This operates on whole elements.
+	pub fn difference_with(&mut self, other: &Self) {
+		self.merge_elements(other, |a, b| a & !b);
+	}
+
+	/// Replaces `self` with the values in exactly one of `self` and `other`.
+	///
+	/// This operates on whole elements, and grows `self` to the length of
+	/// `other` if it is shorter.
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		let olen = other.bits.len();
+		if olen > self.bits.len() {
+			self.bits.resize(olen, false);
+		}
+		self.merge_elements(other, |a, b| (a | b) & !(a & b));
+	}
+
+	/// Tests if every member of `self` is also a member of `other`.
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.difference(other).next().is_none()
+	}
+
+	/// Tests if every member of `other` is also a member of `self`.
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).next().is_none()
+	}
+
+	/// Combines each element of `self` with the element of `other` that holds
+	/// the same members, over the elements that both sets have.
+	///
+	/// Both vectors begin at the front of their first element, so their
+	/// elements line up. The bits of `other` past its length are cleared
+	/// before `func` sees them, so that they do not become members of `self`.
+	fn merge_elements<F>(&mut self, other: &Self, func: F)
+	where F: Fn(T, T) -> T {
+		let len = cmp::min(self.bits.len(), other.bits.len());
+		let elts = elts::<T>(len);
+		let tail = len % T::BITS as usize;
+		let dst = &mut self.bits.as_mut_slice()[.. elts];
+		let src = &other.bits.as_slice()[.. elts];
+		let pairs = dst.iter_mut().zip(src.iter().copied());
+		for (n, (a, mut b)) in pairs.enumerate() {
+			if tail != 0 && n + 1 == elts {
+				BitSlice::<O, T>::from_element_mut(&mut b)[tail ..]
+					.set_all(false);
+			}
+			*a = func(*a, b);
+		}
+	}
+}
+
+/// Sets compare by their members, regardless of the lengths of their
+/// underlying vectors.
+impl<O, T> PartialEq for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn eq(&self, rhs: &Self) -> bool {
+		self.iter().eq(rhs.iter())
+	}
+}
+
+impl<O, T> Eq for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> Hash for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		for n in self.iter() {
+			n.hash(hasher);
+		}
+	}
+}
+
+impl<O, T> Debug for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<O, T> Extend<usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item = usize> {
+		for n in src {
+			self.insert(n);
+		}
+	}
+}
+
+impl<O, T> FromIterator<usize> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<O, T> From<BitVec<O, T>> for BitSet<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: BitVec<O, T>) -> Self {
+		Self::from_bitvec(bits)
+	}
+}
+
+impl<'a, O, T> IntoIterator for &'a BitSet<O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type IntoIter = IterOnes<'a, O, T>;
+	type Item = usize;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** Lazy union of two sets.
+
+This struct is created by the [`union`] method on [`BitSet`].
+
+[`BitSet`]: struct.BitSet.html
+[`union`]: struct.BitSet.html#method.union
+**/
+#[derive(Clone, Debug)]
+pub struct Union<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	a: Peekable<IterOnes<'a, O, T>>,
+	b: Peekable<IterOnes<'a, O, T>>,
+}
+
+impl<'a, O, T> Iterator for Union<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match (self.a.peek(), self.b.peek()) {
+			(Some(x), Some(y)) if x < y => self.a.next(),
+			(Some(x), Some(y)) if x > y => self.b.next(),
+			(Some(_), Some(_)) => {
+				self.b.next();
+				self.a.next()
+			},
+			(Some(_), None) => self.a.next(),
+			(None, _) => self.b.next(),
+		}
+	}
+}
+
+impl<'a, O, T> FusedIterator for Union<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+}
+
+/** Lazy intersection of two sets.
+
+This struct is created by the [`intersection`] method on [`BitSet`].
+
+[`BitSet`]: struct.BitSet.html
+[`intersection`]: struct.BitSet.html#method.intersection
+**/
+#[derive(Clone, Debug)]
+pub struct Intersection<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	a: Peekable<IterOnes<'a, O, T>>,
+	b: Peekable<IterOnes<'a, O, T>>,
+}
+
+impl<'a, O, T> Iterator for Intersection<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let (x, y) = (*self.a.peek()?, *self.b.peek()?);
+			if x < y {
+				self.a.next();
+			}
+			else if x > y {
+				self.b.next();
+			}
+			else {
+				self.b.next();
+				return self.a.next();
+			}
+		}
+	}
+}
+
+impl<'a, O, T> FusedIterator for Intersection<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+}
+
+/** Lazy difference of two sets.
+
+This struct is created by the [`difference`] method on [`BitSet`].
+
+[`BitSet`]: struct.BitSet.html
+[`difference`]: struct.BitSet.html#method.difference
+**/
+#[derive(Clone, Debug)]
+pub struct Difference<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	a: Peekable<IterOnes<'a, O, T>>,
+	b: Peekable<IterOnes<'a, O, T>>,
+}
+
+impl<'a, O, T> Iterator for Difference<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let x = *self.a.peek()?;
+			match self.b.peek() {
+				Some(&y) if y < x => {
+					self.b.next();
+				},
+				Some(&y) if y == x => {
+					self.a.next();
+					self.b.next();
+				},
+				_ => return self.a.next(),
+			}
+		}
+	}
+}
+
+impl<'a, O, T> FusedIterator for Difference<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+}
+
+/** Lazy symmetric difference of two sets.
+
+This struct is created by the [`symmetric_difference`] method on [`BitSet`].
+
+[`BitSet`]: struct.BitSet.html
+[`symmetric_difference`]: struct.BitSet.html#method.symmetric_difference
+**/
+#[derive(Clone, Debug)]
+pub struct SymmetricDifference<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	a: Peekable<IterOnes<'a, O, T>>,
+	b: Peekable<IterOnes<'a, O, T>>,
+}
+
+impl<'a, O, T> Iterator for SymmetricDifference<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			match (self.a.peek(), self.b.peek()) {
+				(Some(x), Some(y)) if x < y => return self.a.next(),
+				(Some(x), Some(y)) if x > y => return self.b.next(),
+				(Some(_), Some(_)) => {
+					self.a.next();
+					self.b.next();
+				},
+				(Some(_), None) => return self.a.next(),
+				(None, _) => return self.b.next(),
+			}
+		}
+	}
+}
+
+impl<'a, O, T> FusedIterator for SymmetricDifference<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::order::Msb0;
+
+	use alloc::vec::Vec;
+
+	/// Builds a pair of overlapping sets, with different vector lengths.
+	fn pair() -> (BitSet<Msb0, u8>, BitSet<Msb0, u8>) {
+		let a = [0, 3, 8, 9, 40, 63].iter().copied().collect();
+		let b = [3, 4, 9, 40, 100].iter().copied().collect();
+		(a, b)
+	}
+
+	#[test]
+	fn membership() {
+		let mut set = BitSet::<Msb0, u8>::new();
+		assert!(set.is_empty());
+		assert!(!set.contains(5));
+		assert!(set.insert(5));
+		assert!(set.insert(70));
+		assert!(!set.insert(5));
+		assert_eq!(set.len(), 2);
+		assert_eq!(set.first(), Some(5));
+		assert_eq!(set.last(), Some(70));
+		assert!(set.remove(70));
+		assert!(!set.remove(70));
+		assert!(!set.remove(1000));
+
+		let shorter = set.clone();
+		set.shrink_to_fit();
+		assert_eq!(set.as_bitslice().len(), 6);
+		assert_eq!(set, shorter);
+		assert_eq!(set.iter().collect::<Vec<_>>(), &[5]);
+	}
+
+	#[test]
+	fn lazy() {
+		let (a, b) = pair();
+		assert_eq!(a.union(&b).collect::<Vec<_>>(), &[
+			0, 3, 4, 8, 9, 40, 63, 100
+		]);
+		assert_eq!(a.intersection(&b).collect::<Vec<_>>(), &[3, 9, 40]);
+		assert_eq!(a.difference(&b).collect::<Vec<_>>(), &[0, 8, 63]);
+		assert_eq!(b.difference(&a).collect::<Vec<_>>(), &[4, 100]);
+		assert_eq!(a.symmetric_difference(&b).collect::<Vec<_>>(), &[
+			0, 4, 8, 63, 100
+		]);
+	}
+
+	#[test]
+	fn in_place() {
+		let (a, b) = pair();
+
+		let mut c = a.clone();
+		c.union_with(&b);
+		assert!(c.iter().eq(a.union(&b)));
+		let mut c = b.clone();
+		c.union_with(&a);
+		assert!(c.iter().eq(a.union(&b)));
+
+		let mut c = a.clone();
+		c.intersect_with(&b);
+		assert!(c.iter().eq(a.intersection(&b)));
+		let mut c = b.clone();
+		c.intersect_with(&a);
+		assert!(c.iter().eq(a.intersection(&b)));
+
+		let mut c = a.clone();
+		c.difference_with(&b);
+		assert!(c.iter().eq(a.difference(&b)));
+		let mut c = b.clone();
+		c.difference_with(&a);
+		assert!(c.iter().eq(b.difference(&a)));
+
+		let mut c = a.clone();
+		c.symmetric_difference_with(&b);
+		assert!(c.iter().eq(a.symmetric_difference(&b)));
+		let mut c = b.clone();
+		c.symmetric_difference_with(&a);
+		assert!(c.iter().eq(a.symmetric_difference(&b)));
+	}
+
+	/// The in-place operations ignore stale bits past the end of `other`, and
+	/// sets built from vectors that do not begin at the front of an element.
+	#[test]
+	fn in_place_edges() {
+		let mut bv = bitvec![Msb0, u8; 1; 16];
+		bv.truncate(11);
+		let stale = BitSet::from_bitvec(bv);
+		let offset = BitSet::from_bitvec(bitvec![Msb0, u8; 1; 20][6 ..].into());
+		assert!(offset.iter().eq(0 .. 14));
+
+		let mut c: BitSet<Msb0, u8> = [0, 12, 15].iter().copied().collect();
+		c.union_with(&stale);
+		assert!(c.iter().eq((0 .. 11).chain([12, 15].iter().copied())));
+
+		let mut c: BitSet<Msb0, u8> = [0, 12, 15].iter().copied().collect();
+		c.difference_with(&stale);
+		assert!(c.iter().eq([12, 15].iter().copied()));
+
+		let mut c: BitSet<Msb0, u8> = [0, 12, 15].iter().copied().collect();
+		c.symmetric_difference_with(&stale);
+		assert!(c.iter().eq((1 .. 11).chain([12, 15].iter().copied())));
+
+		let mut c: BitSet<Msb0, u8> = [0, 12, 15].iter().copied().collect();
+		c.intersect_with(&stale);
+		assert!(c.iter().eq([0].iter().copied()));
+
+		let mut c = stale.clone();
+		c.symmetric_difference_with(&offset);
+		assert!(c.iter().eq(11 .. 14));
+	}
+
+	#[test]
+	fn relations() {
+		let (a, b) = pair();
+		let sub: BitSet<Msb0, u8> = [3, 40].iter().copied().collect();
+		let far: BitSet<Msb0, u8> = [1, 200].iter().copied().collect();
+		assert!(sub.is_subset(&a));
+		assert!(sub.is_subset(&b));
+		assert!(a.is_superset(&sub));
+		assert!(!a.is_subset(&b));
+		assert!(far.is_disjoint(&a));
+		assert!(!a.is_disjoint(&b));
+		assert!(BitSet::new().is_subset(&far));
+	}
+}
@@ -11,7 +11,11 @@ Rust slices, and must never be interchanged except through the provided APIs.
 use crate::{
 	access::BitAccess,
 	domain::*,
-	indices::Indexable,
+	error::BitError,
+	indices::{
+		BitIdx,
+		Indexable,
+	},
 	order::{
 		BitOrder,
 		Local,
@@ -20,7 +24,13 @@ use crate::{
 	store::BitStore,
 };
 
-use core::marker::PhantomData;
+use core::{
+	cell::UnsafeCell,
+	marker::PhantomData,
+};
+
+#[cfg(feature = "atomic")]
+use core::sync::atomic::Ordering;
 
 use either::Either;
 
@@ -106,7 +116,12 @@ where
 	/// alone.
 	_type: PhantomData<T>,
 	/// Slice of elements `T` over which the `BitSlice` has usage.
-	_elts: [()],
+	///
+	/// Slices that share an element, such as the halves produced by
+	/// `split_at_mut`, may each write it through `T::Access`, so the memory
+	/// behind a `&BitSlice` can change while the reference is held. The cell
+	/// tells the compiler not to assume otherwise.
+	_elts: [UnsafeCell<()>],
 }
 
 impl<O, T> BitSlice<O, T>
@@ -316,6 +331,53 @@ where
 		unsafe { self.set_unchecked(index, value) };
 	}
 
+	/// Sets the bit value at the given position, without panicking.
+	///
+	/// This is the fallible counterpart to [`set`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to set.
+	/// - `value`: The value to be set, `true` for `1` and `false` for `0`.
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::OutOfBounds`], and leaves the slice unchanged, if
+	/// `index` is outside the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::error::BitError;
+	/// use bitvec::prelude::*;
+	///
+	/// let mut store = 0u8;
+	/// let bits = store.bits_mut::<Msb0>();
+	/// assert!(bits.try_set(3, true).is_ok());
+	/// assert_eq!(
+	///     bits.try_set(8, true),
+	///     Err(BitError::OutOfBounds { index: 8, len: 8 }),
+	/// );
+	/// assert_eq!(store, 0x10);
+	/// ```
+	///
+	/// [`BitError::OutOfBounds`]: ../error/enum.BitError.html
+	/// [`set`]: #method.set
+	pub fn try_set(
+		&mut self,
+		index: usize,
+		value: bool,
+	) -> Result<(), BitError>
+	{
+		let len = self.len();
+		if index >= len {
+			return Err(BitError::OutOfBounds { index, len });
+		}
+		unsafe { self.set_unchecked(index, value) };
+		Ok(())
+	}
+
 	/// Sets a bit at an index, without doing bounds checking.
 	///
 	/// This is generally not recommended; use with caution! For a safe
@@ -367,6 +429,270 @@ where
 		(*data_ptr.offset(elt)).set::<O>(bit, value);
 	}
 
+	/// Reads the bit value at the given position, with a given memory
+	/// ordering.
+	///
+	/// Ordinary reads of a `BitSlice` use relaxed loads. This method allows a
+	/// `BitSlice` shared across threads to synchronize with writes made by
+	/// [`set_with`], such as when one thread publishes data and then sets a
+	/// ready flag.
+	///
+	/// `u128` has no atomic operations, so `order` has no effect on
+	/// `BitSlice<_, u128>`, which cannot be shared across threads.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to read. It must be in the domain `0 ..
+	///   self.len()`.
+	/// - `order`: The memory ordering of the load.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index`.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is outside the slice domain, or if
+	/// `order` is `Release` or `AcqRel`, which are not valid for loads.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::Ordering;
+	///
+	/// let store = 0x20u8;
+	/// let bits = store.bits::<Msb0>();
+	/// assert!(bits.get_with(2, Ordering::Acquire));
+	/// assert!(!bits.get_with(3, Ordering::SeqCst));
+	/// ```
+	///
+	/// [`set_with`]: #method.set_with
+	#[cfg(feature = "atomic")]
+	pub fn get_with(&self, index: usize, order: Ordering) -> bool {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		match order {
+			Ordering::Release | Ordering::AcqRel => {
+				panic!("There is no {:?} ordering for loads", order);
+			},
+			_ => {},
+		}
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { &*bitptr.pointer().a().offset(elt) }.get_with::<O>(bit, order)
+	}
+
+	/// Sets the bit value at the given position, with a given memory ordering.
+	///
+	/// Ordinary writes to a `BitSlice` use relaxed read/modify/write
+	/// operations. This method allows writes to synchronize with reads made by
+	/// [`get_with`] in other threads.
+	///
+	/// `u128` has no atomic operations, so `order` has no effect on
+	/// `BitSlice<_, u128>`, which cannot be shared across threads.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to set. It must be in the domain `0 ..
+	///   self.len()`.
+	/// - `value`: The value to be set, `true` for `1` and `false` for `0`.
+	/// - `order`: The memory ordering of the read/modify/write operation. All
+	///   orderings are valid.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is outside the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::Ordering;
+	///
+	/// let mut store = 0u8;
+	/// let bits = store.bits_mut::<Lsb0>();
+	/// bits.set_with(4, true, Ordering::Release);
+	/// assert!(bits.get_with(4, Ordering::Acquire));
+	/// assert_eq!(store, 0x10);
+	/// ```
+	///
+	/// [`get_with`]: #method.get_with
+	#[cfg(feature = "atomic")]
+	pub fn set_with(&mut self, index: usize, value: bool, order: Ordering) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { &*bitptr.pointer().a().offset(elt) }
+			.set_with::<O>(bit, value, order);
+	}
+
+	/// Sets the bit value at the given position, returning its previous value.
+	///
+	/// This is a single atomic read/modify/write operation, so exactly one of
+	/// several threads racing to set the same bit observes it as previously
+	/// clear.
+	///
+	/// `u128` has no atomic operations, so `order` has no effect on
+	/// `BitSlice<_, u128>`, which cannot be shared across threads.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to set. It must be in the domain `0 ..
+	///   self.len()`.
+	/// - `value`: The value to be set, `true` for `1` and `false` for `0`.
+	/// - `order`: The memory ordering of the read/modify/write operation. All
+	///   orderings are valid.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index` immediately before the write.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is outside the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::Ordering;
+	///
+	/// let mut store = 0u8;
+	/// let bits = store.bits_mut::<Msb0>();
+	/// assert!(!bits.fetch_set(1, true, Ordering::AcqRel));
+	/// assert!(bits.fetch_set(1, true, Ordering::AcqRel));
+	/// assert_eq!(store, 0x40);
+	/// ```
+	#[cfg(feature = "atomic")]
+	pub fn fetch_set(
+		&mut self,
+		index: usize,
+		value: bool,
+		order: Ordering,
+	) -> bool
+	{
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { &*bitptr.pointer().a().offset(elt) }
+			.fetch_set::<O>(bit, value, order)
+	}
+
+	/// Inverts the bit value at the given position, returning its previous
+	/// value.
+	///
+	/// `u128` has no atomic operations, so `order` has no effect on
+	/// `BitSlice<_, u128>`, which cannot be shared across threads.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to invert. It must be in the domain `0 ..
+	///   self.len()`.
+	/// - `order`: The memory ordering of the read/modify/write operation. All
+	///   orderings are valid.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index` immediately before the inversion.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is outside the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::Ordering;
+	///
+	/// let mut store = 0u8;
+	/// let bits = store.bits_mut::<Lsb0>();
+	/// assert!(!bits.fetch_toggle(3, Ordering::Relaxed));
+	/// assert!(bits[3]);
+	/// assert!(bits.fetch_toggle(3, Ordering::Relaxed));
+	/// assert_eq!(store, 0);
+	/// ```
+	#[cfg(feature = "atomic")]
+	pub fn fetch_toggle(&mut self, index: usize, order: Ordering) -> bool {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { &*bitptr.pointer().a().offset(elt) }
+			.fetch_invert::<O>(bit, order)
+	}
+
+	/// Writes `new` into the bit at the given position only if it currently
+	/// holds `current`.
+	///
+	/// A bit has only two states, so the comparison and the write are a single
+	/// atomic `fetch_or` or `fetch_and` operation, and can never fail
+	/// spuriously.
+	///
+	/// `u128` has no atomic operations, so `order` has no effect on
+	/// `BitSlice<_, u128>`, which cannot be shared across threads.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to exchange. It must be in the domain `0 ..
+	///   self.len()`.
+	/// - `current`: The value the bit must hold for the write to occur.
+	/// - `new`: The value to write into the bit.
+	/// - `order`: The memory ordering of the read/modify/write operation. All
+	///   orderings are valid.
+	///
+	/// # Returns
+	///
+	/// `Ok(current)` if the bit held `current` and now holds `new`, or
+	/// `Err(!current)` if the bit did not hold `current` and was not modified.
+	///
+	/// # Panics
+	///
+	/// This method panics if `index` is outside the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::Ordering;
+	///
+	/// let mut store = 0u8;
+	/// let bits = store.bits_mut::<Lsb0>();
+	/// assert_eq!(
+	///     bits.compare_exchange_bit(0, false, true, Ordering::AcqRel),
+	///     Ok(false),
+	/// );
+	/// assert_eq!(
+	///     bits.compare_exchange_bit(0, false, true, Ordering::AcqRel),
+	///     Err(true),
+	/// );
+	/// assert_eq!(store, 1);
+	/// ```
+	#[cfg(feature = "atomic")]
+	pub fn compare_exchange_bit(
+		&mut self,
+		index: usize,
+		current: bool,
+		new: bool,
+		order: Ordering,
+	) -> Result<bool, bool>
+	{
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		unsafe { &*bitptr.pointer().a().offset(elt) }
+			.compare_exchange_bit::<O>(bit, current, new, order)
+	}
+
 	/// Produces a write reference to a region of the slice.
 	///
 	/// This method corresponds to [`Index::index`], except that it produces a
@@ -732,6 +1058,10 @@ where
 
 	/// Counts how many bits are set high.
 	///
+	/// Partially-occupied edge elements are masked down to their live bits,
+	/// so every element, including those of short or misaligned slices, is
+	/// counted with a single population-count instruction.
+	///
 	/// # Parameters
 	///
 	/// - `&self`
@@ -751,16 +1081,13 @@ where
 	pub fn count_ones(&self) -> usize {
 		match self.bitptr().domain().splat() {
 			Either::Right((h, e, t)) => {
-				let elt = e.load();
-				(*h .. *t).filter(|n| elt.get::<O>(n.idx())).count()
+				(e.load() & O::mask_range(h, t)).count_ones()
 			},
 			Either::Left((h, b, t)) => {
 				let mut out = 0usize;
 				if let Some((h, head)) = h {
-					let elt = head.load();
-					out += (*h .. T::BITS)
-						.filter(|n| elt.get::<O>(n.idx()))
-						.count();
+					let mask = O::mask_range(h, T::BITS.tail());
+					out += (head.load() & mask).count_ones();
 				}
 				if let Some(body) = b {
 					out += body
@@ -770,8 +1097,8 @@ where
 						.sum::<usize>();
 				}
 				if let Some((tail, t)) = t {
-					let elt = tail.load();
-					out += (0 .. *t).filter(|n| elt.get::<O>(n.idx())).count();
+					let mask = O::mask_range(0u8.idx(), t);
+					out += (tail.load() & mask).count_ones();
 				}
 				out
 			},
@@ -800,6 +1127,316 @@ where
 		self.len() - self.count_ones()
 	}
 
+	/// Enumerates the indices of all bits in the slice that are set high.
+	///
+	/// The iterator searches the slice one memory element at a time, so runs
+	/// of elements without any set bits are skipped in a single test each,
+	/// rather than being inspected bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A double-ended iterator which yields, in ascending order, the index of
+	/// each bit in `self` that is `1`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0b0100_0001u8, 0x80].bits::<Msb0>();
+	/// let mut ones = bits.iter_ones();
+	/// assert_eq!(ones.next(), Some(1));
+	/// assert_eq!(ones.next_back(), Some(8));
+	/// assert_eq!(ones.next(), Some(7));
+	/// assert!(ones.next().is_none());
+	/// ```
+	#[inline]
+	pub fn iter_ones(&self) -> IterOnes<O, T> {
+		IterOnes {
+			inner: self,
+			front: 0,
+		}
+	}
+
+	/// Enumerates the indices of all bits in the slice that are set low.
+	///
+	/// The iterator searches the slice one memory element at a time, so runs
+	/// of elements without any unset bits are skipped in a single test each,
+	/// rather than being inspected bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A double-ended iterator which yields, in ascending order, the index of
+	/// each bit in `self` that is `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0xFEu8, 0xDF].bits::<Lsb0>();
+	/// let mut zeros = bits.iter_zeros();
+	/// assert_eq!(zeros.next(), Some(0));
+	/// assert_eq!(zeros.next(), Some(13));
+	/// assert!(zeros.next().is_none());
+	/// ```
+	#[inline]
+	pub fn iter_zeros(&self) -> IterZeros<O, T> {
+		IterZeros {
+			inner: self,
+			front: 0,
+		}
+	}
+
+	/// Finds the index of the first bit in the slice that is set high.
+	///
+	/// The partial edge elements of the slice are masked down to their live
+	/// bits, and each element is then searched with a single bit-scan
+	/// instruction (when the `BitOrder` provides one), rather than bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The lowest index in `self` whose bit is `1`, or `None` if no bits are
+	/// set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x12].bits::<Msb0>();
+	/// assert_eq!(bits.first_one(), Some(11));
+	/// assert_eq!(bits[12 ..].first_one(), Some(2));
+	/// assert!(bits[.. 11].first_one().is_none());
+	/// ```
+	#[inline]
+	pub fn first_one(&self) -> Option<usize> {
+		self.find_first(true)
+	}
+
+	/// Finds the index of the last bit in the slice that is set high.
+	///
+	/// This searches from the back of the slice in the same manner as
+	/// [`first_one`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The highest index in `self` whose bit is `1`, or `None` if no bits are
+	/// set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x12u8, 0].bits::<Lsb0>();
+	/// assert_eq!(bits.last_one(), Some(4));
+	/// assert_eq!(bits[2 ..].last_one(), Some(2));
+	/// assert!(bits[5 ..].last_one().is_none());
+	/// ```
+	///
+	/// [`first_one`]: #method.first_one
+	#[inline]
+	pub fn last_one(&self) -> Option<usize> {
+		self.find_last(true)
+	}
+
+	/// Finds the index of the first bit in the slice that is set low.
+	///
+	/// This searches in the same manner as [`first_one`], after inverting
+	/// each element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The lowest index in `self` whose bit is `0`, or `None` if all bits are
+	/// set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [!0u8, 0xEF].bits::<Msb0>();
+	/// assert_eq!(bits.first_zero(), Some(11));
+	/// assert!(bits[.. 11].first_zero().is_none());
+	/// ```
+	///
+	/// [`first_one`]: #method.first_one
+	#[inline]
+	pub fn first_zero(&self) -> Option<usize> {
+		self.find_first(false)
+	}
+
+	/// Finds the index of the last bit in the slice that is set low.
+	///
+	/// This searches in the same manner as [`last_one`], after inverting each
+	/// element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The highest index in `self` whose bit is `0`, or `None` if all bits are
+	/// set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0xEFu8, !0].bits::<Lsb0>();
+	/// assert_eq!(bits.last_zero(), Some(4));
+	/// assert!(bits[5 ..].last_zero().is_none());
+	/// ```
+	///
+	/// [`last_one`]: #method.last_one
+	#[inline]
+	pub fn last_zero(&self) -> Option<usize> {
+		self.find_last(false)
+	}
+
+	/// Counts the number of bits at the front of the slice that are unset.
+	///
+	/// Counting proceeds in semantic order: from index `0` towards the back of
+	/// the slice, regardless of the `BitOrder` or `BitStore` in use. When the
+	/// slice is interpreted as a number under `Msb0` ordering, this is the
+	/// count of leading zeros of that number.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of `0` bits before the first `1` bit in `self`. This is
+	/// `self.len()` if no bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x20].bits::<Msb0>();
+	/// assert_eq!(bits.leading_zeros(), 10);
+	/// assert_eq!(bits[.. 8].leading_zeros(), 8);
+	/// ```
+	#[inline]
+	pub fn leading_zeros(&self) -> usize {
+		self.first_one().unwrap_or_else(|| self.len())
+	}
+
+	/// Counts the number of bits at the back of the slice that are unset.
+	///
+	/// Counting proceeds in semantic order: from the last index towards index
+	/// `0`, regardless of the `BitOrder` or `BitStore` in use. When the slice is
+	/// interpreted as a number under `Msb0` ordering, this is the count of
+	/// trailing zeros of that number.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of `0` bits after the last `1` bit in `self`. This is
+	/// `self.len()` if no bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x20u8, 0].bits::<Lsb0>();
+	/// assert_eq!(bits.trailing_zeros(), 10);
+	/// assert_eq!(bits[.. 5].trailing_zeros(), 5);
+	/// ```
+	#[inline]
+	pub fn trailing_zeros(&self) -> usize {
+		let len = self.len();
+		self.last_one().map_or(len, |n| len - 1 - n)
+	}
+
+	/// Counts the number of bits at the front of the slice that are set.
+	///
+	/// Counting proceeds in semantic order, as in [`leading_zeros`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of `1` bits before the first `0` bit in `self`. This is
+	/// `self.len()` if all bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [!0u8, 0xDF].bits::<Msb0>();
+	/// assert_eq!(bits.leading_ones(), 10);
+	/// assert_eq!(bits[.. 8].leading_ones(), 8);
+	/// ```
+	///
+	/// [`leading_zeros`]: #method.leading_zeros
+	#[inline]
+	pub fn leading_ones(&self) -> usize {
+		self.first_zero().unwrap_or_else(|| self.len())
+	}
+
+	/// Counts the number of bits at the back of the slice that are set.
+	///
+	/// Counting proceeds in semantic order, as in [`trailing_zeros`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of `1` bits after the last `0` bit in `self`. This is
+	/// `self.len()` if all bits are set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0xDFu8, !0].bits::<Lsb0>();
+	/// assert_eq!(bits.trailing_ones(), 10);
+	/// assert_eq!(bits[.. 5].trailing_ones(), 5);
+	/// ```
+	///
+	/// [`trailing_zeros`]: #method.trailing_zeros
+	#[inline]
+	pub fn trailing_ones(&self) -> usize {
+		let len = self.len();
+		self.last_zero().map_or(len, |n| len - 1 - n)
+	}
+
 	/// Set all bits in the slice to a value.
 	///
 	/// # Parameters
@@ -1049,6 +1686,83 @@ where
 		self.bitptr().as_access_slice()
 	}
 
+	/// Views the elements under the slice, split into the partially-occupied
+	/// edge elements and the fully-occupied body elements.
+	///
+	/// This allows algorithms to process the slice an element at a time,
+	/// without needing to compute its layout or touch the bits of other
+	/// handles.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A [`Domain`] view of the memory under the slice. The body elements are
+	/// ordinary `&[T]` slices; the edge elements are [`PartialElement`]
+	/// handles which only read the live bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::domain::Domain;
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xF0u8, 0x0F, 0xFF];
+	/// let bits = &data.bits::<Msb0>()[2 .. 22];
+	/// let ones = match bits.domain() {
+	///     Domain::Enclave(elem) => elem.load().count_ones(),
+	///     Domain::Region { head, body, tail } => {
+	///         head.map_or(0, |h| h.load().count_ones())
+	///             + body.iter().map(|e| e.count_ones()).sum::<u32>()
+	///             + tail.map_or(0, |t| t.load().count_ones())
+	///     },
+	/// };
+	/// assert_eq!(ones as usize, bits.count_ones());
+	/// ```
+	///
+	/// [`Domain`]: ../domain/enum.Domain.html
+	/// [`PartialElement`]: ../domain/struct.PartialElement.html
+	pub fn domain(&self) -> Domain<O, T> {
+		Domain::new(self.bitptr())
+	}
+
+	/// Views the elements under the slice mutably, split into the
+	/// partially-occupied edge elements and the fully-occupied body elements.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A [`DomainMut`] view of the memory under the slice. The body elements
+	/// are ordinary `&mut [T]` slices; the edge elements are
+	/// [`PartialElementMut`] handles which only read and write the live bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::domain::DomainMut;
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// let bits = &mut data.bits_mut::<Lsb0>()[4 .. 20];
+	/// if let DomainMut::Region { head, body, tail } = bits.domain_mut() {
+	///     head.unwrap().store(!0);
+	///     body[0] = 0x5A;
+	///     tail.unwrap().store(!0);
+	/// }
+	/// assert_eq!(data, [0xF0, 0x5A, 0x0F]);
+	/// ```
+	///
+	/// [`DomainMut`]: ../domain/enum.DomainMut.html
+	/// [`PartialElementMut`]: ../domain/struct.PartialElementMut.html
+	pub fn domain_mut(&mut self) -> DomainMut<O, T> {
+		unsafe { DomainMut::new(self.bitptr()) }
+	}
+
 	/// Accesses the underlying pointer structure.
 	///
 	/// # Parameters
@@ -1065,6 +1779,105 @@ where
 		BitPtr::from_bitslice(self)
 	}
 
+	/// Finds the index of the first bit in the slice with a given value.
+	///
+	/// Each element of the slice domain is loaded once, masked down to its
+	/// live bits, and handed to the `BitOrder` bit-scan.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The bit value to search for.
+	///
+	/// # Returns
+	///
+	/// The lowest index in `self` whose bit is `value`, if any.
+	pub(crate) fn find_first(&self, value: bool) -> Option<usize> {
+		//  Searches for `0` invert each element, so that only `1` is sought.
+		let live = |elt: T| if value { elt } else { !elt };
+		match self.bitptr().domain().splat() {
+			Either::Right((h, e, t)) => {
+				O::first_one(live(e.load()) & O::mask_range(h, t))
+					.map(|n| (*n - *h) as usize)
+			},
+			Either::Left((h, b, t)) => {
+				//  The slice index of the first bit in the next element.
+				let mut base = 0usize;
+				if let Some((h, head)) = h {
+					let mask = O::mask_range(h, T::BITS.tail());
+					if let Some(n) = O::first_one(live(head.load()) & mask) {
+						return Some((*n - *h) as usize);
+					}
+					base += (T::BITS - *h) as usize;
+				}
+				if let Some(body) = b {
+					for elt in body {
+						if let Some(n) = O::first_one(live(elt.load())) {
+							return Some(base + *n as usize);
+						}
+						base += T::BITS as usize;
+					}
+				}
+				if let Some((tail, t)) = t {
+					let mask = O::mask_range(0u8.idx(), t);
+					return O::first_one(live(tail.load()) & mask)
+						.map(|n| base + *n as usize);
+				}
+				None
+			},
+		}
+	}
+
+	/// Finds the index of the last bit in the slice with a given value.
+	///
+	/// This is the reverse of [`find_first`], and searches from the back of
+	/// the slice towards the front.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The bit value to search for.
+	///
+	/// # Returns
+	///
+	/// The highest index in `self` whose bit is `value`, if any.
+	///
+	/// [`find_first`]: #method.find_first
+	pub(crate) fn find_last(&self, value: bool) -> Option<usize> {
+		let live = |elt: T| if value { elt } else { !elt };
+		match self.bitptr().domain().splat() {
+			Either::Right((h, e, t)) => {
+				O::last_one(live(e.load()) & O::mask_range(h, t))
+					.map(|n| (*n - *h) as usize)
+			},
+			Either::Left((h, b, t)) => {
+				//  The slice index of the first bit in the current element.
+				let mut base = h.map_or(0, |(h, _)| (T::BITS - *h) as usize)
+					+ b.map_or(0, <[T::Access]>::len) * T::BITS as usize;
+				if let Some((tail, t)) = t {
+					let mask = O::mask_range(0u8.idx(), t);
+					if let Some(n) = O::last_one(live(tail.load()) & mask) {
+						return Some(base + *n as usize);
+					}
+				}
+				if let Some(body) = b {
+					for elt in body.iter().rev() {
+						base -= T::BITS as usize;
+						if let Some(n) = O::last_one(live(elt.load())) {
+							return Some(base + *n as usize);
+						}
+					}
+				}
+				if let Some((h, head)) = h {
+					let mask = O::mask_range(h, T::BITS.tail());
+					return O::last_one(live(head.load()) & mask)
+						.map(|n| (*n - *h) as usize);
+				}
+				None
+			},
+		}
+	}
+
 	/// Copy a bit from one location in a slice to another.
 	///
 	/// # Parameters
@@ -1081,6 +1894,75 @@ where
 	pub(crate) unsafe fn copy_unchecked(&mut self, from: usize, to: usize) {
 		self.set_unchecked(to, *self.get_unchecked(from));
 	}
+
+	/// Copies all bits of an equal-length slice into `self`, an element at a
+	/// time.
+	///
+	/// Each element of `self` is filled by loading the one or two elements of
+	/// `src` which hold its bits, shifting them to the destination alignment
+	/// with [`BitOrder::realign`], and merging them into memory. The partial
+	/// edge elements of `self` are written through masks, so bits outside
+	/// `self` are never disturbed.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The slice whose bits are copied. It must have the same length
+	///   as `self`; this is only checked in debug builds.
+	///
+	/// [`BitOrder::realign`]: ../order/trait.BitOrder.html#method.realign
+	pub(crate) fn copy_from_shifted(&mut self, src: &Self) {
+		debug_assert_eq!(self.len(), src.len(), "Copies require equal lengths");
+		let bits = T::BITS as usize;
+		let src_head = *src.bitptr().head() as usize;
+		let src_elts = src.bitptr().as_access_slice();
+		//  Loads the `n` bits of `src` that begin at slice index `idx`, and
+		//  moves them to begin at element index `to`.
+		let gather = |idx: usize, to: BitIdx<T>, n: usize| -> T {
+			let start = src_head + idx;
+			let (elt, from) = (start / bits, (start % bits) as u8);
+			let out = O::realign(src_elts[elt].load(), from.idx(), to);
+			//  The bits that did not fit in the first element begin the next.
+			let avail = bits - from as usize;
+			if n > avail {
+				let to = (*to as usize + avail) as u8;
+				out | O::realign(src_elts[elt + 1].load(), 0u8.idx(), to.idx())
+			}
+			else {
+				out
+			}
+		};
+
+		match self.bitptr().domain().splat() {
+			Either::Right((h, e, t)) => {
+				let mask = O::mask_range(h, t);
+				e.clear_bits(!mask);
+				e.set_bits(gather(0, h, self.len()) & mask);
+			},
+			Either::Left((h, b, t)) => {
+				//  The slice index of the first bit in the next element.
+				let mut idx = 0usize;
+				if let Some((h, head)) = h {
+					let mask = O::mask_range(h, T::BITS.tail());
+					let n = (T::BITS - *h) as usize;
+					head.clear_bits(!mask);
+					head.set_bits(gather(0, h, n) & mask);
+					idx += n;
+				}
+				if let Some(body) = b {
+					for elt in body {
+						elt.store(gather(idx, 0u8.idx(), bits));
+						idx += bits;
+					}
+				}
+				if let Some((tail, t)) = t {
+					let mask = O::mask_range(0u8.idx(), t);
+					tail.clear_bits(!mask);
+					tail.set_bits(gather(idx, 0u8.idx(), *t as usize) & mask);
+				}
+			},
+		}
+	}
 }
 
 /** Allows a type to be used as a sequence of immutable bits.
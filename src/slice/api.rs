@@ -12,6 +12,7 @@ be documented in a section called `API Differences`.
 
 use crate::{
 	access::BitAccess,
+	error::BitError,
 	indices::BitIdx,
 	order::BitOrder,
 	pointer::BitPtr,
@@ -1214,6 +1215,47 @@ where
 		unsafe { self.split_at_unchecked(mid) }
 	}
 
+	/// Divides one slice into two at an index, without panicking.
+	///
+	/// This is the fallible counterpart to [`split_at`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::OutOfBounds`] if `mid > len`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use bitvec::error::BitError;
+	///
+	/// let data = 0x0Fu8;
+	/// let bits = data.bits::<Msb0>();
+	///
+	/// let (left, right) = bits.try_split_at(4).unwrap();
+	/// assert!(left.not_any());
+	/// assert!(right.all());
+	///
+	/// assert_eq!(
+	///     bits.try_split_at(9),
+	///     Err(BitError::OutOfBounds { index: 9, len: 8 }),
+	/// );
+	/// ```
+	///
+	/// [`BitError::OutOfBounds`]: ../error/enum.BitError.html
+	/// [`split_at`]: #method.split_at
+	pub fn try_split_at(
+		&self,
+		mid: usize,
+	) -> Result<(&Self, &Self), BitError>
+	{
+		let len = self.len();
+		if mid > len {
+			return Err(BitError::OutOfBounds { index: mid, len });
+		}
+		Ok(unsafe { self.split_at_unchecked(mid) })
+	}
+
 	/// Divides one mutable slice into two at an index.
 	///
 	/// The first will contain all indices from `[0, mid)` (excluding the index
@@ -1782,6 +1824,43 @@ where
 		}
 	}
 
+	/// Rotates the slice in-place such that the first `by` bits of the slice
+	/// move to the end, without panicking.
+	///
+	/// This is the fallible counterpart to [`rotate_left`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::OutOfBounds`], and leaves the slice unchanged, if
+	/// `by` is greater than the length of the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use bitvec::error::BitError;
+	///
+	/// let mut data = 0xF0u8;
+	/// let bits = data.bits_mut::<Msb0>();
+	/// assert!(bits.try_rotate_left(2).is_ok());
+	/// assert_eq!(
+	///     bits.try_rotate_left(9),
+	///     Err(BitError::OutOfBounds { index: 9, len: 8 }),
+	/// );
+	/// assert_eq!(data, 0xC3);
+	/// ```
+	///
+	/// [`BitError::OutOfBounds`]: ../error/enum.BitError.html
+	/// [`rotate_left`]: #method.rotate_left
+	pub fn try_rotate_left(&mut self, by: usize) -> Result<(), BitError> {
+		let len = self.len();
+		if by > len {
+			return Err(BitError::OutOfBounds { index: by, len });
+		}
+		self.rotate_left(by);
+		Ok(())
+	}
+
 	/// Rotates the slice in-place such that the first `self.len() - by` bits of
 	/// the slice move to the end while the last `by` bits move to the front.
 	/// After calling `rotate_right`, the bit previously at index
@@ -1862,8 +1941,10 @@ where
 	///
 	/// The length of `src` must be the same as `self`.
 	///
-	/// This is equivalent to `copy_from_slice`; this function is only included
-	/// for API surface equivalence.
+	/// Because `src` may have a different ordering or storage type than
+	/// `self`, this copies one bit at a time. When both slices have the same
+	/// type, [`copy_from_slice`] moves whole elements instead, and is much
+	/// faster.
 	///
 	/// # Panics
 	///
@@ -1903,6 +1984,8 @@ where
 	/// head.clone_from_slice(tail);
 	/// assert_eq!(data, 0x33);
 	/// ```
+	///
+	/// [`copy_from_slice`]: #method.copy_from_slice
 	pub fn clone_from_slice<P, U>(&mut self, src: &BitSlice<P, U>)
 	where
 		P: BitOrder,
@@ -1925,8 +2008,10 @@ where
 	/// The length of `src` must be the same as `self`.
 	///
 	/// This is restricted to take exactly the same type of bit slice as the
-	/// source slice, so that the implementation has the chace to use faster
-	/// `memcpy` if possible.
+	/// source slice, so that the implementation can copy whole elements. Each
+	/// destination element is filled by loading the source elements that hold
+	/// its bits and shifting them into place, so the two slices do not need to
+	/// begin at the same offset within their elements.
 	///
 	/// # Panics
 	///
@@ -1967,7 +2052,12 @@ where
 	/// assert_eq!(data, 0x33);
 	/// ```
 	pub fn copy_from_slice(&mut self, src: &Self) {
-		self.clone_from_slice(src)
+		assert_eq!(
+			self.len(),
+			src.len(),
+			"Copying from slice requires equal lengths",
+		);
+		self.copy_from_shifted(src);
 	}
 
 	/// Swaps all bits in `self` with those in `other`.
@@ -2072,3 +2072,110 @@ where
 	T: 'a + BitStore,
 {
 }
+
+/** An iterator over the indices of the set bits in a `BitSlice`.
+
+This struct is created by the [`iter_ones`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`iter_ones`]: struct.BitSlice.html#method.iter_ones
+**/
+#[derive(Clone, Debug)]
+pub struct IterOnes<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The `BitSlice` region that has not yet been searched.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The index, in the original slice, of the first bit in `inner`.
+	pub(super) front: usize,
+}
+
+/** An iterator over the indices of the unset bits in a `BitSlice`.
+
+This struct is created by the [`iter_zeros`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`iter_zeros`]: struct.BitSlice.html#method.iter_zeros
+**/
+#[derive(Clone, Debug)]
+pub struct IterZeros<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The `BitSlice` region that has not yet been searched.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The index, in the original slice, of the first bit in `inner`.
+	pub(super) front: usize,
+}
+
+macro_rules! index_iterator {
+	($name:ident, $value:expr) => {
+		impl<'a, O, T> Iterator for $name<'a, O, T>
+		where
+			O: BitOrder,
+			T: 'a + BitStore,
+		{
+			type Item = usize;
+
+			#[inline]
+			fn next(&mut self) -> Option<Self::Item> {
+				match self.inner.find_first($value) {
+					Some(n) => {
+						self.inner =
+							unsafe { self.inner.get_unchecked(n + 1 ..) };
+						let out = self.front + n;
+						self.front = out + 1;
+						Some(out)
+					},
+					None => {
+						self.inner = BitSlice::empty();
+						None
+					},
+				}
+			}
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				(0, Some(self.inner.len()))
+			}
+
+			#[inline]
+			fn last(mut self) -> Option<Self::Item> {
+				self.next_back()
+			}
+		}
+
+		impl<'a, O, T> DoubleEndedIterator for $name<'a, O, T>
+		where
+			O: BitOrder,
+			T: 'a + BitStore,
+		{
+			#[inline]
+			fn next_back(&mut self) -> Option<Self::Item> {
+				match self.inner.find_last($value) {
+					Some(n) => {
+						self.inner = unsafe { self.inner.get_unchecked(.. n) };
+						Some(self.front + n)
+					},
+					None => {
+						self.inner = BitSlice::empty();
+						None
+					},
+				}
+			}
+		}
+
+		impl<'a, O, T> FusedIterator for $name<'a, O, T>
+		where
+			O: BitOrder,
+			T: 'a + BitStore,
+		{
+		}
+	};
+}
+
+index_iterator!(IterOnes, true);
+index_iterator!(IterZeros, false);
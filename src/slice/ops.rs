@@ -629,9 +629,10 @@ where
 			self.set_all(false);
 			return;
 		}
-		//  If the slice fully owns its memory, then a fast path is available
-		//  with element-wise `memmove`.
-		if self.bitptr().domain().is_spanning() {
+		//  If the slice fully owns its memory, and the shift moves at least one
+		//  whole element, then a fast path is available with element-wise
+		//  `memmove`.
+		if self.bitptr().domain().is_spanning() && shamt >= T::BITS as usize {
 			//  Compute the shift distance measured in elements.
 			let offset = shamt >> T::INDX;
 			//  Compute the number of elements that will remain.
@@ -663,7 +664,7 @@ where
 			}
 			//  Any remaining shift amount only needs to shift the `after` block
 			//  above.
-			self[.. rem << T::INDX] <<= shamt & T::INDX as usize;
+			self[.. rem << T::INDX] <<= shamt & T::MASK as usize;
 			return;
 		}
 		//  Otherwise, crawl.
@@ -738,9 +739,10 @@ where
 			self.set_all(false);
 			return;
 		}
-		//  If the slice fully owns its memory, then a fast path is available
-		//  with element-wise `memmove`.
-		if self.bitptr().domain().is_spanning() {
+		//  If the slice fully owns its memory, and the shift moves at least one
+		//  whole element, then a fast path is available with element-wise
+		//  `memmove`.
+		if self.bitptr().domain().is_spanning() && shamt >= T::BITS as usize {
 			//  Compute the shift amount measured in elements.
 			let offset = shamt >> T::INDX;
 			// Compute the number of elements that will remain.
@@ -765,7 +767,7 @@ where
 			}
 			//  Any remaining shift amount only needs to shift the `after` block
 			//  above.
-			self[offset << T::INDX ..] >>= shamt & T::INDX as usize;
+			self[offset << T::INDX ..] >>= shamt & T::MASK as usize;
 			return;
 		}
 		//  Otherwise, crawl.
@@ -3,15 +3,20 @@
 
 use crate::{
 	order::{
+		BitOrder,
 		Local,
+		Lsb0,
 		Msb0,
 	},
 	slice::{
 		AsBits,
 		BitSlice,
 	},
+	store::BitStore,
 };
 
+use core::cmp;
+
 #[test]
 fn all() {
 	assert!(BitSlice::<Local, usize>::empty().all());
@@ -79,6 +84,35 @@ fn count_ones() {
 	assert_eq!([0x0Fu8, !0].bits::<Local>()[2 ..].count_ones(), 10);
 	assert_eq!([!0u8, 0xF0].bits::<Local>()[.. 14].count_ones(), 10);
 	assert_eq!((!0u8).bits::<Local>().count_ones(), 8);
+
+	let bytes = [0x5Au8, 0xC3, !0, 0x81];
+	check_counts(bytes.bits::<Msb0>());
+	check_counts(bytes.bits::<Lsb0>());
+
+	let shorts = [0x8421u16, 0x7FFE];
+	check_counts(shorts.bits::<Msb0>());
+	check_counts(shorts.bits::<Lsb0>());
+
+	let ints = [0xDEAD_BEEFu32, 0x0000_FFFF];
+	check_counts(ints.bits::<Msb0>());
+	check_counts(ints.bits::<Lsb0>());
+}
+
+/// Checks the population counts of every subslice against bit-wise counting.
+fn check_counts<O, T>(bits: &BitSlice<O, T>)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let len = bits.len();
+	for start in 0 .. len {
+		for end in start ..= len {
+			let bits = &bits[start .. end];
+			let ones = bits.iter().filter(|b| **b).count();
+			assert_eq!(bits.count_ones(), ones);
+			assert_eq!(bits.count_zeros(), bits.len() - ones);
+		}
+	}
 }
 
 #[test]
@@ -94,6 +128,216 @@ fn count_zeros() {
 	assert_eq!(0u8.bits::<Local>().count_zeros(), 8);
 }
 
+/// Checks the index iterators of every subslice against bit-wise enumeration.
+fn check_index_iters<O, T>(bits: &BitSlice<O, T>)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let len = bits.len();
+	for start in 0 .. len {
+		for end in start ..= len {
+			let bits = &bits[start .. end];
+			let ones = || {
+				bits.iter().enumerate().filter(|(_, b)| **b).map(|(n, _)| n)
+			};
+			let zeros = || {
+				bits.iter().enumerate().filter(|(_, b)| !**b).map(|(n, _)| n)
+			};
+			assert!(bits.iter_ones().eq(ones()));
+			assert!(bits.iter_ones().rev().eq(ones().rev()));
+			assert!(bits.iter_zeros().eq(zeros()));
+			assert!(bits.iter_zeros().rev().eq(zeros().rev()));
+
+			//  Alternate between the two ends.
+			let mut iter = bits.iter_ones();
+			let (mut front, mut back) = (ones(), ones().rev());
+			let mut remaining = bits.count_ones();
+			while remaining > 0 {
+				assert_eq!(iter.next(), front.next());
+				remaining -= 1;
+				if remaining > 0 {
+					assert_eq!(iter.next_back(), back.next());
+					remaining -= 1;
+				}
+			}
+			assert!(iter.next().is_none());
+			assert!(iter.next_back().is_none());
+		}
+	}
+}
+
+#[test]
+fn iter_ones_zeros() {
+	assert!(BitSlice::<Local, usize>::empty().iter_ones().next().is_none());
+	assert!(BitSlice::<Local, usize>::empty().iter_zeros().next().is_none());
+
+	let bytes = [0x81u8, 0, 0x10, !0, 0x3C];
+	check_index_iters(bytes.bits::<Msb0>());
+	check_index_iters(bytes.bits::<Lsb0>());
+
+	let shorts = [0x0180u16, 0, 0xFFF7];
+	check_index_iters(shorts.bits::<Msb0>());
+	check_index_iters(shorts.bits::<Lsb0>());
+
+	let ints = [0x8000_0001u32, !0];
+	check_index_iters(ints.bits::<Msb0>());
+	check_index_iters(ints.bits::<Lsb0>());
+}
+
+/// Checks the bit searches of every subslice against bit-wise enumeration.
+fn check_searches<O, T>(bits: &BitSlice<O, T>)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let len = bits.len();
+	for start in 0 .. len {
+		for end in start ..= len {
+			let bits = &bits[start .. end];
+			assert_eq!(bits.first_one(), bits.iter().position(|b| *b));
+			assert_eq!(bits.last_one(), bits.iter().rposition(|b| *b));
+			assert_eq!(bits.first_zero(), bits.iter().position(|b| !*b));
+			assert_eq!(bits.last_zero(), bits.iter().rposition(|b| !*b));
+
+			let lead = |v: bool| bits.iter().take_while(|b| **b == v).count();
+			let trail =
+				|v: bool| bits.iter().rev().take_while(|b| **b == v).count();
+			assert_eq!(bits.leading_zeros(), lead(false));
+			assert_eq!(bits.leading_ones(), lead(true));
+			assert_eq!(bits.trailing_zeros(), trail(false));
+			assert_eq!(bits.trailing_ones(), trail(true));
+		}
+	}
+}
+
+#[test]
+fn first_last() {
+	let empty = BitSlice::<Local, usize>::empty();
+	assert!(empty.first_one().is_none());
+	assert!(empty.last_one().is_none());
+	assert!(empty.first_zero().is_none());
+	assert!(empty.last_zero().is_none());
+	assert_eq!(empty.leading_zeros(), 0);
+	assert_eq!(empty.trailing_ones(), 0);
+
+	let bytes = [0u8, 0x24, !0, 0, 0xDB];
+	check_searches(bytes.bits::<Msb0>());
+	check_searches(bytes.bits::<Lsb0>());
+
+	let shorts = [0u16, 0x8001, !0];
+	check_searches(shorts.bits::<Msb0>());
+	check_searches(shorts.bits::<Lsb0>());
+
+	let ints = [!0u32, 0, 0x0001_0000];
+	check_searches(ints.bits::<Msb0>());
+	check_searches(ints.bits::<Lsb0>());
+}
+
+/// Checks copies between every pair of source and destination offsets, for
+/// every length, against bit-wise comparison.
+fn check_copies<O, T>(src: &BitSlice<O, T>, dst: &mut BitSlice<O, T>)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let dlen = dst.len();
+	for from in 0 .. src.len() {
+		for to in 0 .. dlen {
+			for len in 0 ..= cmp::min(src.len() - from, dlen - to) {
+				for &fill in &[false, true] {
+					dst.set_all(fill);
+					dst[to ..][.. len].copy_from_slice(&src[from ..][.. len]);
+					assert!(dst[.. to].iter().all(|b| *b == fill));
+					assert!(dst[to + len ..].iter().all(|b| *b == fill));
+					assert_eq!(&dst[to ..][.. len], &src[from ..][.. len]);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn copy_from_slice() {
+	let bytes = [0x5Au8, 0xC3, 0x81];
+	check_copies(bytes.bits::<Msb0>(), [0u8; 3].bits_mut::<Msb0>());
+	check_copies(bytes.bits::<Lsb0>(), [0u8; 3].bits_mut::<Lsb0>());
+
+	let shorts = [0x8421u16, 0x7FFE];
+	check_copies(shorts.bits::<Msb0>(), [0u16; 2].bits_mut::<Msb0>());
+	check_copies(shorts.bits::<Lsb0>(), [0u16; 2].bits_mut::<Lsb0>());
+
+	let ints = [0xDEAD_BEEFu32, 0x0000_FFFF];
+	check_copies(&ints.bits::<Msb0>()[27 ..], [0u32; 2].bits_mut::<Msb0>());
+	check_copies(&ints.bits::<Lsb0>()[27 ..], [0u32; 2].bits_mut::<Lsb0>());
+
+	let wide = [0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128, !0 << 100];
+	check_copies(
+		&wide.bits::<Msb0>()[120 .. 140],
+		&mut [0u128; 2].bits_mut::<Msb0>()[110 .. 150],
+	);
+	check_copies(
+		&wide.bits::<Lsb0>()[120 .. 140],
+		&mut [0u128; 2].bits_mut::<Lsb0>()[110 .. 150],
+	);
+
+	//  Sharing an element between source and destination is permitted.
+	let mut data = 0x0Fu8;
+	let (head, tail) = data.bits_mut::<Lsb0>().split_at_mut(4);
+	head[1 ..].copy_from_slice(&tail[.. 3]);
+	assert_eq!(data, 0x01);
+}
+
+/// Checks both shift directions, for every distance, against bit-wise
+/// movement.
+fn check_shifts<O, T>(src: &BitSlice<O, T>, dst: &mut BitSlice<O, T>)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let len = src.len();
+	for shamt in 0 ..= len + 1 {
+		dst.copy_from_slice(src);
+		*dst <<= shamt;
+		for idx in 0 .. len {
+			let expected = idx + shamt < len && src[idx + shamt];
+			assert_eq!(dst[idx], expected, "{} << {}", idx, shamt);
+		}
+
+		dst.copy_from_slice(src);
+		*dst >>= shamt;
+		for idx in 0 .. len {
+			let expected = idx >= shamt && src[idx - shamt];
+			assert_eq!(dst[idx], expected, "{} >> {}", idx, shamt);
+		}
+	}
+}
+
+#[test]
+fn shifts() {
+	let bytes = [0x5Au8, 0xC3, 0x81, 0x7E];
+	check_shifts(bytes.bits::<Msb0>(), [0u8; 4].bits_mut::<Msb0>());
+	check_shifts(bytes.bits::<Lsb0>(), [0u8; 4].bits_mut::<Lsb0>());
+	check_shifts(
+		&bytes.bits::<Msb0>()[3 .. 29],
+		&mut [0u8; 4].bits_mut::<Msb0>()[5 .. 31],
+	);
+
+	let ints = [0xDEAD_BEEFu32, 0x0000_FFFF, 0x8000_0001];
+	check_shifts(ints.bits::<Msb0>(), [0u32; 3].bits_mut::<Msb0>());
+	check_shifts(ints.bits::<Lsb0>(), [0u32; 3].bits_mut::<Lsb0>());
+
+	let wide = [0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128, 1];
+	check_shifts(
+		&wide.bits::<Msb0>()[3 .. 200],
+		&mut [0u128; 2].bits_mut::<Msb0>()[50 .. 247],
+	);
+	check_shifts(
+		&wide.bits::<Lsb0>()[3 .. 200],
+		&mut [0u128; 2].bits_mut::<Lsb0>()[50 .. 247],
+	);
+}
+
 #[test]
 fn set_all() {
 	let mut data = [0u8; 5];
@@ -110,3 +354,190 @@ fn set_all() {
 	bits.set_all(true);
 	assert_eq!(data, [!0; 5]);
 }
+
+#[test]
+#[cfg(feature = "atomic")]
+fn orderings() {
+	use core::sync::atomic::Ordering;
+
+	let mut data = [0u16; 2];
+	let bits = &mut data.bits_mut::<Msb0>()[3 .. 29];
+	bits.set_with(0, true, Ordering::Release);
+	bits.set_with(20, true, Ordering::SeqCst);
+	bits.set_with(25, true, Ordering::Relaxed);
+	bits.set_with(25, false, Ordering::AcqRel);
+	assert!(bits.get_with(0, Ordering::Acquire));
+	assert!(bits.get_with(20, Ordering::SeqCst));
+	assert!(!bits.get_with(25, Ordering::Relaxed));
+	assert_eq!(data, [0x1000, 0x0100]);
+}
+
+#[test]
+#[cfg(feature = "atomic")]
+#[should_panic(expected = "There is no Release ordering for loads")]
+fn orderings_load() {
+	use core::sync::atomic::Ordering;
+
+	0u8.bits::<Lsb0>().get_with(0, Ordering::Release);
+}
+
+/// Threads that share an element through `split_at_mut` each publish their
+/// half of it.
+#[test]
+#[cfg(all(feature = "atomic", feature = "std"))]
+fn orderings_threads() {
+	use core::sync::atomic::Ordering;
+	use std::{
+		boxed::Box,
+		thread,
+	};
+
+	let data: &'static mut [u8; 1] = Box::leak(Box::new([0u8]));
+	let ptr = data.as_ptr();
+	let (low, high) = data.bits_mut::<Lsb0>().split_at_mut(4);
+	let threads = vec![
+		thread::spawn(move || {
+			for idx in 0 .. 4 {
+				low.set_with(idx, idx % 2 == 0, Ordering::Release);
+			}
+		}),
+		thread::spawn(move || {
+			for idx in 0 .. 4 {
+				high.set_with(idx, idx % 2 == 1, Ordering::Release);
+			}
+		}),
+	];
+	for thread in threads {
+		thread.join().unwrap();
+	}
+	assert_eq!(unsafe { *ptr }, 0xA5);
+}
+
+#[test]
+#[cfg(feature = "atomic")]
+fn fetch_ops() {
+	use core::sync::atomic::Ordering;
+
+	let mut data = [0u32; 2];
+	let bits = &mut data.bits_mut::<Lsb0>()[5 .. 60];
+	assert!(!bits.fetch_set(0, true, Ordering::AcqRel));
+	assert!(bits.fetch_set(0, true, Ordering::Relaxed));
+	assert!(!bits.fetch_toggle(30, Ordering::SeqCst));
+	assert!(bits.fetch_toggle(30, Ordering::SeqCst));
+	assert!(!bits.fetch_toggle(30, Ordering::SeqCst));
+
+	assert_eq!(
+		bits.compare_exchange_bit(54, true, false, Ordering::AcqRel),
+		Err(false),
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(54, false, true, Ordering::AcqRel),
+		Ok(false),
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(54, true, true, Ordering::Release),
+		Ok(true),
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(54, false, false, Ordering::Release),
+		Err(true),
+	);
+	assert_eq!(
+		bits.compare_exchange_bit(0, true, false, Ordering::Relaxed),
+		Ok(true),
+	);
+	assert_eq!(data, [0, 1 << 3 | 1 << 27]);
+
+	let mut wide = 0u128;
+	let bits = wide.bits_mut::<Msb0>();
+	assert!(!bits.fetch_set(100, true, Ordering::SeqCst));
+	assert!(bits.fetch_toggle(100, Ordering::SeqCst));
+	assert_eq!(
+		bits.compare_exchange_bit(127, false, true, Ordering::SeqCst),
+		Ok(false),
+	);
+	assert_eq!(wide, 1);
+}
+
+/// Threads that toggle their own halves of one element never lose each
+/// other's updates.
+#[test]
+#[cfg(all(feature = "atomic", feature = "std"))]
+fn fetch_ops_threads() {
+	use core::sync::atomic::Ordering;
+	use std::{
+		boxed::Box,
+		thread,
+		vec::Vec,
+	};
+
+	let data: &'static mut [u8; 1] = Box::leak(Box::new([0u8]));
+	let ptr = data.as_ptr();
+	let (low, high) = data.bits_mut::<Lsb0>().split_at_mut(4);
+	let threads = vec![
+		thread::spawn(move || {
+			let mut cleared = 0;
+			for _ in 0 .. 1001 {
+				for idx in 0 .. 4 {
+					cleared += !low.fetch_toggle(idx, Ordering::AcqRel) as usize;
+				}
+			}
+			cleared
+		}),
+		thread::spawn(move || {
+			let mut cleared = 0;
+			for _ in 0 .. 1001 {
+				for idx in 0 .. 4 {
+					cleared += high
+						.compare_exchange_bit(idx, false, true, Ordering::AcqRel)
+						.is_ok() as usize;
+					high.fetch_set(idx, false, Ordering::AcqRel);
+				}
+			}
+			cleared
+		}),
+	];
+	let cleared = threads
+		.into_iter()
+		.map(|t| t.join().unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(cleared, [501 * 4, 1001 * 4]);
+	assert_eq!(unsafe { *ptr }, 0x0F);
+}
+
+#[test]
+fn fallible() {
+	use crate::error::BitError;
+
+	let mut data = [0u8; 3];
+	let bits = &mut data.bits_mut::<Msb0>()[2 .. 22];
+	assert_eq!(bits.try_set(19, true), Ok(()));
+	assert_eq!(
+		bits.try_set(20, true),
+		Err(BitError::OutOfBounds { index: 20, len: 20 }),
+	);
+
+	assert_eq!(bits.try_store(0x0ABCu32), Ok(()));
+	assert_eq!(bits.try_load::<u32>(), Ok(0x0ABC));
+	assert_eq!(
+		bits.try_load::<u16>(),
+		Err(BitError::FieldWidth { len: 20, width: 16 }),
+	);
+	assert_eq!(
+		bits.try_store(0u8),
+		Err(BitError::FieldWidth { len: 20, width: 8 }),
+	);
+
+	bits.set_all(false);
+	bits.set(0, true);
+	assert_eq!(bits.try_rotate_left(8), Ok(()));
+	assert_eq!(bits.first_one(), Some(12));
+	assert_eq!(
+		bits.try_rotate_left(21),
+		Err(BitError::OutOfBounds { index: 21, len: 20 }),
+	);
+
+	let (left, right) = bits.try_split_at(20).unwrap();
+	assert_eq!((left.len(), right.len()), (20, 0));
+	assert!(bits.try_split_at(21).is_err());
+}
@@ -502,12 +502,15 @@ println!("{:02X}", unsafe { SRC });
 Without atomic operations, this is logically a data race. With atomic
 operations, each read/modify/write cycle is guaranteed to exclude other threads
 from observing the location until the writeback completes.
+
+`u128` has no atomic operations, so `BitSlice<_, u128>` is never `Send`.
 **/
 #[cfg(feature = "atomic")]
 unsafe impl<O, T> Send for BitSlice<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
+	T::Access: Sync,
 {
 }
 
@@ -521,6 +524,7 @@ unsafe impl<O, T> Sync for BitSlice<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
+	T::Access: Sync,
 {
 }
 
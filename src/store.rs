@@ -38,19 +38,19 @@ use core::{
 	},
 };
 
-use radium::marker::BitOps;
+use core::cell::Cell;
 
 #[cfg(feature = "atomic")]
 use core::sync::atomic;
 
-#[cfg(not(feature = "atomic"))]
-use core::cell::Cell;
-
 /** Generalizes over the fundamental types for use in `bitvec` data structures.
 
-This trait must only be implemented on unsigned integer primitives with full
-alignment. It cannot be implemented on `u128` on any architecture, or on `u64`
-on 32-bit systems.
+This trait must only be implemented on unsigned integer primitives. It cannot be
+implemented on `u64` on 32-bit systems.
+
+`u128` has no atomic counterpart on any architecture, so it is always accessed
+through `Cell`, even when the `atomic` feature is enabled. As such, `BitSlice`
+regions over `u128` cannot be sent or shared across threads.
 
 The `Sealed` supertrait ensures that this can only be implemented locally, and
 will never be implemented by downstream crates on new types.
@@ -87,7 +87,6 @@ pub trait BitStore:
 	+ Sized
 	+ Sync
 	+ UpperHex
-	+ BitOps
 {
 	/// The width, in bits, of this type.
 	const BITS: u8 = size_of::<Self>() as u8 * 8;
@@ -248,6 +247,55 @@ pub trait BitStore:
 		//  invert (0 becomes 1, 1 becomes 0), zero-extend, count ones
 		<Self as BitStore>::count_ones(!self)
 	}
+
+	/// Counts how many `0` bits are more significant than the most significant
+	/// `1` bit in `self`.
+	///
+	/// This forwards to the inherent `leading_zeros` method of the fundamental.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The number of leading zeros in `self`. This is `Self::BITS` when `self`
+	/// is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::BitStore;
+	/// assert_eq!(BitStore::leading_zeros(0u8), 8);
+	/// assert_eq!(BitStore::leading_zeros(1u8), 7);
+	/// assert_eq!(BitStore::leading_zeros(128u8), 0);
+	/// ```
+	fn leading_zeros(self) -> usize;
+
+	/// Counts how many `0` bits are less significant than the least
+	/// significant `1` bit in `self`.
+	///
+	/// This forwards to the inherent `trailing_zeros` method of the
+	/// fundamental.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The number of trailing zeros in `self`. This is `Self::BITS` when `self`
+	/// is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::BitStore;
+	/// assert_eq!(BitStore::trailing_zeros(0u8), 8);
+	/// assert_eq!(BitStore::trailing_zeros(1u8), 0);
+	/// assert_eq!(BitStore::trailing_zeros(128u8), 7);
+	/// ```
+	fn trailing_zeros(self) -> usize;
 }
 
 /** Compute the number of elements required to store a number of bits.
@@ -288,6 +336,16 @@ macro_rules! bitstore {
 			fn count_ones(self) -> usize {
 				Self::count_ones(self) as usize
 			}
+
+			#[inline(always)]
+			fn leading_zeros(self) -> usize {
+				Self::leading_zeros(self) as usize
+			}
+
+			#[inline(always)]
+			fn trailing_zeros(self) -> usize {
+				Self::trailing_zeros(self) as usize
+			}
 		}
 	)* };
 }
@@ -309,6 +367,10 @@ bitstore! {
 	usize => 8, atomic::AtomicUsize;
 }
 
+bitstore! {
+	u128 => 16, Cell<u128>;
+}
+
 #[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64")))]
 compile_fail!("This architecture is currently not supported. File an issue at https://github.com/myrrlyn/bitvec");
 
@@ -317,7 +379,7 @@ compile_fail!("This architecture is currently not supported. File an issue at ht
 This trait is public in the module, so that other modules in the crate can use
 it, but so long as it is not exported by the crate root and this module is
 private, this trait effectively forbids downstream implementation of the
-`BitStore` trait.
+`BitStore` and `fields::Signed` traits.
 **/
 #[doc(hidden)]
 pub trait Sealed {}
@@ -328,7 +390,7 @@ macro_rules! seal {
 	)* };
 }
 
-seal!(u8, u16, u32, usize);
+seal!(u8, u16, u32, u128, usize, i8, i16, i32, isize);
 
 #[cfg(target_pointer_width = "64")]
-seal!(u64);
+seal!(u64, i64);
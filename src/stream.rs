@@ -0,0 +1,579 @@
+/*! Bit-granular streams
+
+This module provides cursors that write values into, and read values out of, a
+bit sequence one field at a time, tracking the position of the next field
+themselves. Each field is transferred with a single [`BitField`] load or store,
+rather than bit by bit.
+
+[`BitField`]: ../fields/trait.BitField.html
+!*/
+
+pub use crate::fields::Endian;
+
+use crate::{
+	fields::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+/** The error produced when a stream has too few bits left for a request.
+
+Writers over a fixed `BitSlice` produce this when they are full, and readers
+produce it when they reach the end of their slice. The stream is not advanced.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OutOfBits {
+	/// The number of bits that the request needed.
+	pub requested: usize,
+	/// The number of bits that were left in the stream.
+	pub remaining: usize,
+}
+
+impl Display for OutOfBits {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"Requested {} bits from a stream with {} remaining",
+			self.requested, self.remaining,
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBits {
+}
+
+/** Writes fields into a bit sequence, one after another.
+
+The writer either fills a fixed `BitSlice`, failing with [`OutOfBits`] when it
+is full, or appends to the end of a `BitVec`, growing it as needed.
+
+# Type Parameters
+
+- `O`: The ordering of the target. Every field is stored with the `BitField`
+  implementation for `BitSlice<O, T>`.
+- `T`: The storage type of the target.
+
+# Examples
+
+This writes the first word of an IPv4 header, whose fields are all in network
+byte order:
+
+```rust
+use bitvec::prelude::*;
+use bitvec::stream::{BitWriter, Endian};
+
+let mut header = [0u8; 4];
+let mut out = BitWriter::new(header.bits_mut::<Msb0>(), Endian::Big);
+out.write_bits(4u8, 4).unwrap();
+out.write_bits(5u8, 4).unwrap();
+out.write_u8(0).unwrap();
+out.write_u16(0x0123).unwrap();
+assert_eq!(out.position(), 32);
+assert!(out.write_bool(true).is_err());
+
+assert_eq!(header, [0x45, 0x00, 0x01, 0x23]);
+```
+
+[`OutOfBits`]: struct.OutOfBits.html
+**/
+#[derive(Debug)]
+pub struct BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The sequence being written.
+	sink: Sink<'a, O, T>,
+	/// The index in `sink` of the next bit to write.
+	pos: usize,
+	/// The element significance order used for each field.
+	endian: Endian,
+}
+
+/// The targets that a `BitWriter` can write into.
+#[derive(Debug)]
+enum Sink<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	Slice(&'a mut BitSlice<O, T>),
+	#[cfg(feature = "alloc")]
+	Vec(&'a mut BitVec<O, T>),
+}
+
+impl<'a, O, T> BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Creates a writer that fills a bit slice from its start.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice to fill. Writes past its end fail.
+	/// - `endian`: The element significance order of each field.
+	pub fn new(bits: &'a mut BitSlice<O, T>, endian: Endian) -> Self {
+		Self {
+			sink: Sink::Slice(bits),
+			pos: 0,
+			endian,
+		}
+	}
+
+	/// Creates a writer that appends to a bit vector.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The vector to extend. Writing starts at its current end, and
+	///   never fails for lack of space.
+	/// - `endian`: The element significance order of each field.
+	#[cfg(feature = "alloc")]
+	pub fn append(bits: &'a mut BitVec<O, T>, endian: Endian) -> Self {
+		Self {
+			pos: bits.len(),
+			sink: Sink::Vec(bits),
+			endian,
+		}
+	}
+
+	/// Gets the index in the target of the next bit to be written.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Counts the bits that can still be written.
+	///
+	/// # Returns
+	///
+	/// The number of bits left in a slice target, or `None` for a vector
+	/// target, which grows without limit.
+	pub fn remaining(&self) -> Option<usize> {
+		match &self.sink {
+			Sink::Slice(bits) => Some(bits.len() - self.pos),
+			#[cfg(feature = "alloc")]
+			Sink::Vec(_) => None,
+		}
+	}
+
+	/// Gets the element significance order used for each field.
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the element significance order used for later fields.
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Writes the low bits of a value as a field.
+	///
+	/// # Parameters
+	///
+	/// - `value`: The value to write. Bits above the lowest `count` are
+	///   ignored.
+	/// - `count`: The width of the field. A width of zero writes nothing.
+	///
+	/// # Returns
+	///
+	/// An error if the target has fewer than `count` bits left, in which case
+	/// nothing is written.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is wider than `U`.
+	pub fn write_bits<U>(
+		&mut self,
+		value: U,
+		count: usize,
+	) -> Result<(), OutOfBits>
+	where
+		U: BitStore,
+	{
+		if count == 0 {
+			return Ok(());
+		}
+		assert!(
+			count <= U::BITS as usize,
+			"Cannot write {} bits from a {}-bit value",
+			count,
+			U::BITS,
+		);
+		let endian = self.endian;
+		self.reserve(count)?.store_endian(value, endian);
+		self.pos += count;
+		Ok(())
+	}
+
+	/// Writes a single bit.
+	pub fn write_bool(&mut self, value: bool) -> Result<(), OutOfBits> {
+		self.reserve(1)?.set(0, value);
+		self.pos += 1;
+		Ok(())
+	}
+
+	/// Writes an eight-bit field.
+	pub fn write_u8(&mut self, value: u8) -> Result<(), OutOfBits> {
+		self.write_bits(value, 8)
+	}
+
+	/// Writes a sixteen-bit field.
+	pub fn write_u16(&mut self, value: u16) -> Result<(), OutOfBits> {
+		self.write_bits(value, 16)
+	}
+
+	/// Writes a thirty-two-bit field.
+	pub fn write_u32(&mut self, value: u32) -> Result<(), OutOfBits> {
+		self.write_bits(value, 32)
+	}
+
+	/// Writes a sixty-four-bit field.
+	#[cfg(target_pointer_width = "64")]
+	pub fn write_u64(&mut self, value: u64) -> Result<(), OutOfBits> {
+		self.write_bits(value, 64)
+	}
+
+	/// Writes `0` bits until the position is a multiple of `align`.
+	///
+	/// # Returns
+	///
+	/// An error if the target has too few bits left to reach the alignment, in
+	/// which case nothing is written.
+	///
+	/// # Panics
+	///
+	/// This panics if `align` is zero.
+	pub fn align_to(&mut self, align: usize) -> Result<(), OutOfBits> {
+		assert!(align > 0, "Cannot align to a multiple of zero");
+		let pad = (align - self.pos % align) % align;
+		self.reserve(pad)?.set_all(false);
+		self.pos += pad;
+		Ok(())
+	}
+
+	/// Produces the next `count` bits of the target, growing a vector target
+	/// as needed. This does not advance the position.
+	fn reserve(
+		&mut self,
+		count: usize,
+	) -> Result<&mut BitSlice<O, T>, OutOfBits>
+	{
+		let pos = self.pos;
+		match &mut self.sink {
+			Sink::Slice(bits) => {
+				let remaining = bits.len() - pos;
+				if count > remaining {
+					return Err(OutOfBits {
+						requested: count,
+						remaining,
+					});
+				}
+				Ok(&mut bits[pos .. pos + count])
+			},
+			#[cfg(feature = "alloc")]
+			Sink::Vec(bits) => {
+				bits.resize(pos + count, false);
+				Ok(&mut bits[pos ..])
+			},
+		}
+	}
+}
+
+/** Reads fields out of a bit slice, one after another.
+
+Reads past the end of the slice fail with [`OutOfBits`] and leave the reader
+where it was, so a caller can recover from a truncated input.
+
+# Type Parameters
+
+- `O`: The ordering of the source. Every field is loaded with the `BitField`
+  implementation for `BitSlice<O, T>`.
+- `T`: The storage type of the source.
+
+# Examples
+
+This reads the first word of an IPv4 header:
+
+```rust
+use bitvec::prelude::*;
+use bitvec::stream::{BitReader, Endian};
+
+let header = [0x45u8, 0x00, 0x01, 0x23];
+let mut src = BitReader::new(header.bits::<Msb0>(), Endian::Big);
+assert_eq!(src.read_bits::<u8>(4), Ok(4));
+assert_eq!(src.peek::<u8>(4), Ok(5));
+src.skip(12).unwrap();
+assert_eq!(src.read_u16(), Ok(0x0123));
+assert_eq!(src.bits_remaining(), 0);
+assert!(src.read_bool().is_err());
+```
+
+[`OutOfBits`]: struct.OutOfBits.html
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The sequence being read.
+	bits: &'a BitSlice<O, T>,
+	/// The index in `bits` of the next bit to read.
+	pos: usize,
+	/// The element significance order used for each field.
+	endian: Endian,
+}
+
+impl<'a, O, T> BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Creates a reader at the start of a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice to read.
+	/// - `endian`: The element significance order of each field.
+	pub fn new(bits: &'a BitSlice<O, T>, endian: Endian) -> Self {
+		Self {
+			bits,
+			pos: 0,
+			endian,
+		}
+	}
+
+	/// Gets the index in the source of the next bit to be read.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Counts the bits that have not yet been read.
+	pub fn bits_remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Accesses the bits that have not yet been read.
+	pub fn remainder(&self) -> &'a BitSlice<O, T> {
+		&self.bits[self.pos ..]
+	}
+
+	/// Gets the element significance order used for each field.
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the element significance order used for later fields.
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Reads a field into the low bits of a value.
+	///
+	/// # Parameters
+	///
+	/// - `count`: The width of the field. A width of zero reads nothing, and
+	///   produces zero.
+	///
+	/// # Returns
+	///
+	/// The field, zero-extended to `U`, or an error if the source has fewer
+	/// than `count` bits left, in which case the reader does not advance.
+	///
+	/// # Panics
+	///
+	/// This panics if `count` is wider than `U`.
+	pub fn read_bits<U>(&mut self, count: usize) -> Result<U, OutOfBits>
+	where U: BitStore {
+		let value = self.peek(count)?;
+		self.pos += count;
+		Ok(value)
+	}
+
+	/// Reads a field without advancing past it.
+	///
+	/// This behaves exactly as [`read_bits`], except that the position is
+	/// left unchanged.
+	///
+	/// [`read_bits`]: #method.read_bits
+	pub fn peek<U>(&self, count: usize) -> Result<U, OutOfBits>
+	where U: BitStore {
+		assert!(
+			count <= U::BITS as usize,
+			"Cannot read {} bits into a {}-bit value",
+			count,
+			U::BITS,
+		);
+		let field = self.field(count)?;
+		if count == 0 {
+			return Ok(U::FALSE);
+		}
+		Ok(field.load_endian(self.endian))
+	}
+
+	/// Reads a single bit.
+	pub fn read_bool(&mut self) -> Result<bool, OutOfBits> {
+		let bit = self.field(1)?[0];
+		self.pos += 1;
+		Ok(bit)
+	}
+
+	/// Reads an eight-bit field.
+	pub fn read_u8(&mut self) -> Result<u8, OutOfBits> {
+		self.read_bits(8)
+	}
+
+	/// Reads a sixteen-bit field.
+	pub fn read_u16(&mut self) -> Result<u16, OutOfBits> {
+		self.read_bits(16)
+	}
+
+	/// Reads a thirty-two-bit field.
+	pub fn read_u32(&mut self) -> Result<u32, OutOfBits> {
+		self.read_bits(32)
+	}
+
+	/// Reads a sixty-four-bit field.
+	#[cfg(target_pointer_width = "64")]
+	pub fn read_u64(&mut self) -> Result<u64, OutOfBits> {
+		self.read_bits(64)
+	}
+
+	/// Advances past `count` bits without reading them.
+	pub fn skip(&mut self, count: usize) -> Result<(), OutOfBits> {
+		self.field(count)?;
+		self.pos += count;
+		Ok(())
+	}
+
+	/// Advances until the position is a multiple of `align`.
+	///
+	/// # Returns
+	///
+	/// An error if the source ends before the alignment is reached, in which
+	/// case the reader does not advance.
+	///
+	/// # Panics
+	///
+	/// This panics if `align` is zero.
+	pub fn align_to(&mut self, align: usize) -> Result<(), OutOfBits> {
+		assert!(align > 0, "Cannot align to a multiple of zero");
+		self.skip((align - self.pos % align) % align)
+	}
+
+	/// Produces the next `count` bits of the source. This does not advance the
+	/// position.
+	fn field(&self, count: usize) -> Result<&'a BitSlice<O, T>, OutOfBits> {
+		let remaining = self.bits_remaining();
+		if count > remaining {
+			return Err(OutOfBits {
+				requested: count,
+				remaining,
+			});
+		}
+		Ok(&self.bits[self.pos .. self.pos + count])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn writer_slice() {
+		let mut data = [0u16; 2];
+		let mut out = BitWriter::new(data.bits_mut::<Lsb0>(), Endian::Little);
+		out.write_bool(true).unwrap();
+		out.write_bits(0x1234u16, 12).unwrap();
+		assert_eq!(out.position(), 13);
+		out.align_to(8).unwrap();
+		assert_eq!(out.position(), 16);
+		out.write_bits(!0u8, 3).unwrap();
+		assert_eq!(out.remaining(), Some(13));
+		assert_eq!(
+			out.write_u16(0),
+			Err(OutOfBits {
+				requested: 16,
+				remaining: 13,
+			}),
+		);
+		assert_eq!(out.position(), 19);
+		out.align_to(32).unwrap();
+		assert!(out.align_to(7).is_err());
+		assert_eq!(data, [0x234 << 1 | 1, 0b111]);
+	}
+
+	#[test]
+	fn writer_vec() {
+		let mut bv = bitvec![Msb0, u8; 1; 3];
+		let mut out = BitWriter::append(&mut bv, Endian::Big);
+		assert_eq!(out.position(), 3);
+		assert_eq!(out.remaining(), None);
+		out.align_to(8).unwrap();
+		out.write_u16(0xABCD).unwrap();
+		out.set_endian(Endian::Little);
+		out.write_bits(0x1FFu16, 9).unwrap();
+		out.write_bool(false).unwrap();
+		assert_eq!(out.endian(), Endian::Little);
+		assert_eq!(bv.len(), 34);
+		assert_eq!(bv[.. 8].load::<u8>(), 0xE0);
+		assert_eq!(bv[8 .. 24].load_be::<u16>(), 0xABCD);
+		assert_eq!(bv[24 .. 33].load_le::<u16>(), 0x1FF);
+		assert!(!bv[33]);
+	}
+
+	#[test]
+	fn reader() {
+		let data = [0x0234u16 << 1 | 1, 0b111];
+		let mut src = BitReader::new(data.bits::<Lsb0>(), Endian::Little);
+		assert_eq!(src.read_bool(), Ok(true));
+		assert_eq!(src.peek::<u16>(12), Ok(0x234));
+		assert_eq!(src.read_bits::<u16>(12), Ok(0x234));
+		assert_eq!(src.read_bits::<u8>(0), Ok(0));
+		src.align_to(8).unwrap();
+		assert_eq!(src.position(), 16);
+		assert_eq!(src.remainder(), &data.bits::<Lsb0>()[16 ..]);
+		assert_eq!(src.read_bits::<u8>(4), Ok(0b0111));
+		assert_eq!(
+			src.read_u16(),
+			Err(OutOfBits {
+				requested: 16,
+				remaining: 12,
+			}),
+		);
+		assert_eq!(src.position(), 20);
+		assert!(src.align_to(64).is_err());
+		src.skip(12).unwrap();
+		assert_eq!(src.bits_remaining(), 0);
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut bv = BitVec::<Msb0, u8>::new();
+		let mut out = BitWriter::append(&mut bv, Endian::Big);
+		for n in 1 .. 17u16 {
+			out.write_bits(n.wrapping_mul(0x9E37), n as usize).unwrap();
+		}
+		let mut src = BitReader::new(&bv, Endian::Big);
+		for n in 1 .. 17u16 {
+			let mask = !0u16 >> (16 - n);
+			assert_eq!(
+				src.read_bits::<u16>(n as usize),
+				Ok(n.wrapping_mul(0x9E37) & mask),
+			);
+		}
+		assert_eq!(src.bits_remaining(), 0);
+	}
+}
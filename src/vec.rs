@@ -12,6 +12,7 @@ slice and vector types.
 use crate::{
 	access::BitAccess,
 	boxed::BitBox,
+	error::BitError,
 	indices::Indexable,
 	order::{
 		BitOrder,
@@ -28,6 +29,8 @@ use alloc::{
 };
 
 use core::{
+	alloc::Layout,
+	cmp,
 	marker::PhantomData,
 	mem,
 };
@@ -846,6 +849,82 @@ where
 		mem::forget(v);
 		out
 	}
+
+	/// Grows the buffer to hold at least `additional` more bits, reporting
+	/// failure rather than aborting.
+	///
+	/// `Vec` has no fallible reservation in this crate’s minimum supported
+	/// compiler, so this resizes the buffer through the global allocator
+	/// directly. It uses the same layout that `Vec<T>` uses for the same
+	/// capacity, so `with_vec` and the destructor continue to manage the buffer
+	/// afterwards.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of bits, past the current length, that the
+	///   buffer must be able to hold.
+	/// - `exact`: Whether to allocate only the required elements, rather than
+	///   at least doubling the capacity as `Vec::reserve` does.
+	///
+	/// # Errors
+	///
+	/// `BitError::TooLong` if the new length exceeds `BitPtr::<T>::MAX_BITS`,
+	/// or `BitError::AllocFailed` if the allocator refuses the request. The
+	/// vector is unchanged in either case.
+	fn try_grow(
+		&mut self,
+		additional: usize,
+		exact: bool,
+	) -> Result<(), BitError>
+	{
+		let len = self.len().saturating_add(additional);
+		let max = BitPtr::<T>::MAX_BITS;
+		if len > max {
+			return Err(BitError::TooLong { len, max });
+		}
+		let head = self.pointer.head();
+		let (elts, _) = head.span(len);
+		if elts <= self.capacity {
+			return Ok(());
+		}
+		let cap = if exact {
+			elts
+		}
+		else {
+			//  Doubling must not outgrow the largest buffer a vector can use.
+			let (most, _) = head.span(max);
+			cmp::max(elts, cmp::min(self.capacity.saturating_mul(2), most))
+		};
+		let (size, align) = (mem::size_of::<T>(), mem::align_of::<T>());
+		//  Neither capacity can overflow the layout, as `MAX_BITS` keeps the
+		//  buffer well below `isize::MAX` bytes.
+		let new = unsafe {
+			let layout = Layout::from_size_align_unchecked(cap * size, align);
+			if self.capacity == 0 {
+				alloc::alloc::alloc(layout)
+			}
+			else {
+				let old = Layout::from_size_align_unchecked(
+					self.capacity * size,
+					align,
+				);
+				let ptr = self.pointer.pointer().w() as *mut u8;
+				alloc::alloc::realloc(ptr, old, cap * size)
+			}
+		};
+		if new.is_null() {
+			return Err(BitError::AllocFailed {
+				size: cap * size,
+				align,
+			});
+		}
+		unsafe {
+			self.pointer.set_pointer(new as *mut T);
+		}
+		self.capacity = cap;
+		Ok(())
+	}
 }
 
 mod api;
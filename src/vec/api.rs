@@ -3,6 +3,7 @@
 use super::*;
 
 use crate::{
+	error::BitError,
 	order::BitOrder,
 	pointer::BitPtr,
 	store::BitStore,
@@ -65,6 +66,35 @@ where
 		}
 	}
 
+	/// Constructs a new, empty `BitVec<C, T>` with the specified capacity,
+	/// without aborting when the allocation fails.
+	///
+	/// This is the fallible counterpart to [`with_capacity`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::TooLong`] if `capacity` exceeds
+	/// `BitPtr::<T>::MAX_BITS`, or [`BitError::AllocFailed`] if the allocator
+	/// cannot provide the buffer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = BitVec::<Local, u8>::try_with_capacity(100).unwrap();
+	/// assert!(bv.capacity() >= 100);
+	/// assert!(bv.is_empty());
+	/// ```
+	///
+	/// [`BitError::AllocFailed`]: ../error/enum.BitError.html
+	/// [`BitError::TooLong`]: ../error/enum.BitError.html
+	/// [`with_capacity`]: #method.with_capacity
+	pub fn try_with_capacity(capacity: usize) -> Result<Self, BitError> {
+		let mut out = Self::new();
+		out.try_reserve_exact(capacity)?;
+		Ok(out)
+	}
+
 	/// Returns the number of bits the vector can hold without reallocating.
 	///
 	/// # Examples
@@ -112,6 +142,40 @@ where
 		}
 	}
 
+	/// Reserves capacity for at least `additional` more bits, without
+	/// panicking when the vector would grow too long or aborting when the
+	/// allocation fails.
+	///
+	/// This is the fallible counterpart to [`reserve`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::TooLong`] if `self.len() + additional` exceeds
+	/// `BitPtr::<T>::MAX_BITS`, or [`BitError::AllocFailed`] if the allocator
+	/// cannot grow the buffer. The vector is unchanged in either case.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use bitvec::error::BitError;
+	///
+	/// let mut bv = bitvec![1];
+	/// assert!(bv.try_reserve(10).is_ok());
+	/// assert!(bv.capacity() >= 11);
+	/// match bv.try_reserve(!0) {
+	///     Err(BitError::TooLong { len, .. }) => assert_eq!(len, !0),
+	///     _ => unreachable!(),
+	/// }
+	/// ```
+	///
+	/// [`BitError::AllocFailed`]: ../error/enum.BitError.html
+	/// [`BitError::TooLong`]: ../error/enum.BitError.html
+	/// [`reserve`]: #method.reserve
+	pub fn try_reserve(&mut self, additional: usize) -> Result<(), BitError> {
+		self.try_grow(additional, false)
+	}
+
 	/// Reserves the minimum capacity for exactly `additional` more bits to be
 	/// inserted in the given `BitVec<C, T>`. After calling `reserve_exact`,
 	/// capacity will be greater than or equal to `self.len() + additional`.
@@ -132,6 +196,11 @@ where
 	/// let mut bv = bitvec![1];
 	/// bv.reserve_exact(10);
 	/// assert!(bv.capacity() >= 11);
+	///
+	/// let mut bv = BitVec::<Local, u8>::with_capacity(16);
+	/// bv.push(true);
+	/// bv.reserve_exact(24);
+	/// assert!(bv.capacity() >= 25);
 	/// ```
 	pub fn reserve_exact(&mut self, additional: usize) {
 		let newlen = self.len() + additional;
@@ -142,8 +211,41 @@ where
 			BitPtr::<T>::MAX_BITS,
 		);
 		let (total_elts, _) = self.pointer.head().span(newlen);
-		let extra = total_elts - self.capacity;
-		self.with_vec(|v| v.reserve_exact(extra));
+		if let Some(extra) = total_elts.checked_sub(self.pointer.elements()) {
+			self.with_vec(|v| v.reserve_exact(extra));
+		}
+	}
+
+	/// Reserves the minimum capacity for exactly `additional` more bits,
+	/// without panicking when the vector would grow too long or aborting when
+	/// the allocation fails.
+	///
+	/// This is the fallible counterpart to [`reserve_exact`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::TooLong`] if `self.len() + additional` exceeds
+	/// `BitPtr::<T>::MAX_BITS`, or [`BitError::AllocFailed`] if the allocator
+	/// cannot grow the buffer. The vector is unchanged in either case.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Lsb0, u8; 1];
+	/// assert!(bv.try_reserve_exact(20).is_ok());
+	/// assert!(bv.capacity() >= 21);
+	/// ```
+	///
+	/// [`BitError::AllocFailed`]: ../error/enum.BitError.html
+	/// [`BitError::TooLong`]: ../error/enum.BitError.html
+	/// [`reserve_exact`]: #method.reserve_exact
+	pub fn try_reserve_exact(
+		&mut self,
+		additional: usize,
+	) -> Result<(), BitError>
+	{
+		self.try_grow(additional, true)
 	}
 
 	/// Shrinks the capacity of the vector as much as possible.
@@ -397,6 +499,52 @@ where
 		unsafe { self.get_unchecked_mut(index ..) }.rotate_right(1);
 	}
 
+	/// Inserts a bit at position `index` within the vector, without
+	/// panicking.
+	///
+	/// This is the fallible counterpart to [`insert`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::OutOfBounds`] if `index > len`,
+	/// [`BitError::TooLong`] if the vector is already at its maximum length, or
+	/// [`BitError::AllocFailed`] if the allocator cannot grow the buffer. The
+	/// vector is unchanged in each case.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use bitvec::error::BitError;
+	///
+	/// let mut bv = bitvec![1, 0, 1];
+	/// assert!(bv.try_insert(1, true).is_ok());
+	/// assert_eq!(
+	///     bv.try_insert(5, true),
+	///     Err(BitError::OutOfBounds { index: 5, len: 4 }),
+	/// );
+	/// assert_eq!(bv, bitvec![1, 1, 0, 1]);
+	/// ```
+	///
+	/// [`BitError::AllocFailed`]: ../error/enum.BitError.html
+	/// [`BitError::OutOfBounds`]: ../error/enum.BitError.html
+	/// [`BitError::TooLong`]: ../error/enum.BitError.html
+	/// [`insert`]: #method.insert
+	pub fn try_insert(
+		&mut self,
+		index: usize,
+		value: bool,
+	) -> Result<(), BitError>
+	{
+		let len = self.len();
+		if index > len {
+			return Err(BitError::OutOfBounds { index, len });
+		}
+		self.try_reserve(1)?;
+		self.insert(index, value);
+		Ok(())
+	}
+
 	/// Removes and returns the bit at position `index` within the vector,
 	/// shifting all bits after it to the left.
 	///
@@ -477,7 +625,7 @@ where
 	pub fn push(&mut self, value: bool) {
 		let len = self.len();
 		assert!(
-			len <= BitPtr::<T>::MAX_BITS,
+			len < BitPtr::<T>::MAX_BITS,
 			"Capacity overflow: {} >= {}",
 			len,
 			BitPtr::<T>::MAX_BITS,
@@ -495,6 +643,36 @@ where
 		}
 	}
 
+	/// Appends a bit to the back of the vector, without panicking when the
+	/// vector is full or aborting when the allocation fails.
+	///
+	/// This is the fallible counterpart to [`push`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::TooLong`] if the vector is already
+	/// `BitPtr::<T>::MAX_BITS` long, or [`BitError::AllocFailed`] if the
+	/// allocator cannot grow the buffer. The vector is unchanged in either
+	/// case.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv: BitVec = BitVec::new();
+	/// assert!(bv.try_push(true).is_ok());
+	/// assert_eq!(bv, bitvec![1]);
+	/// ```
+	///
+	/// [`BitError::AllocFailed`]: ../error/enum.BitError.html
+	/// [`BitError::TooLong`]: ../error/enum.BitError.html
+	/// [`push`]: #method.push
+	pub fn try_push(&mut self, value: bool) -> Result<(), BitError> {
+		self.try_reserve(1)?;
+		self.push(value);
+		Ok(())
+	}
+
 	/// Removes the last element from a vector and returns it, or `None` if it
 	/// is empty.
 	///
@@ -786,6 +964,43 @@ where
 		self[len ..].clone_from_slice(other)
 	}
 
+	/// Clones and appends all bits in a bit-slice to the `BitVec`, without
+	/// panicking when the vector would grow too long or aborting when the
+	/// allocation fails.
+	///
+	/// This is the fallible counterpart to [`extend_from_slice`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BitError::TooLong`] if `self.len() + other.len()` exceeds
+	/// `BitPtr::<T>::MAX_BITS`, or [`BitError::AllocFailed`] if the allocator
+	/// cannot grow the buffer. The vector is unchanged in either case.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![1];
+	/// assert!(bv.try_extend_from_slice(0x0Fu8.bits::<Msb0>()).is_ok());
+	/// assert_eq!(bv, bitvec![1, 0, 0, 0, 0, 1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`BitError::AllocFailed`]: ../error/enum.BitError.html
+	/// [`BitError::TooLong`]: ../error/enum.BitError.html
+	/// [`extend_from_slice`]: #method.extend_from_slice
+	pub fn try_extend_from_slice<D, U>(
+		&mut self,
+		other: &BitSlice<D, U>,
+	) -> Result<(), BitError>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.try_reserve(other.len())?;
+		self.extend_from_slice(other);
+		Ok(())
+	}
+
 	/// Creates a splicing iterator that replaces the specified range in the
 	/// vector with the given `replace_with` iterator and yields the removed
 	/// bits. `replace_with` does not need to be the same length as `range`.
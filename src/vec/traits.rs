@@ -673,10 +673,12 @@ where
 {
 }
 
-/// `&BitVec` is safe to move across thread boundaries.
+/// `&BitVec` is safe to move across thread boundaries when its elements are
+/// accessed atomically, as it lends out the `&BitSlice` that it owns.
 unsafe impl<O, T> Sync for BitVec<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
+	T::Access: Sync,
 {
 }
@@ -0,0 +1,421 @@
+/*! Volatile bit views of memory-mapped registers
+
+Ordinary `BitSlice` access routes every read and write through the element’s
+`Cell` or atomic access type. The compiler is free to merge, reörder, or
+discard those accesses when it can prove that no other observer exists, which
+is correct for memory but not for device registers, where every read and write
+is itself an effect.
+
+This module provides `VolatileBitSlice`, a handle to a region of registers that
+performs every access with `ptr::read_volatile` and `ptr::write_volatile`. A
+single-bit write is a whole-register read, modify, and write; a [`BitField`]
+transfer reads every register under the field once and, when storing, writes
+each of them back once, in ascending address order.
+
+[`BitField`]: ../fields/trait.BitField.html
+!*/
+
+use crate::{
+	indices::Indexable,
+	order::{
+		BitOrder,
+		Local,
+	},
+	pointer::BitPtr,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
+	store::BitStore,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+	ops::RangeBounds,
+};
+
+/// The most elements that a `BitField` transfer can touch: 128 bits which
+/// begin at the last bit of a byte.
+const FIELD_ELTS: usize = 17;
+
+/** A bit-level view of a region of memory-mapped registers.
+
+Every read of a bit is a volatile read of the register holding it, and every
+write of a bit is a volatile read of its register followed by a volatile write
+of the whole modified register. `VolatileBitSlice` never reads or writes the
+registers it governs through a `&BitSlice` or `&T` reference, so no access is
+ever made that the caller did not request.
+
+The handle has the borrow semantics of `&'a mut [T]`: it can be narrowed with
+[`slice`] and [`split_at`], or temporarily lent with [`reborrow`], but not
+copied.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering of bits within each register.
+- `T: BitStore`: The register type. Each access reads or writes one whole `T`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::volatile::VolatileBitSlice;
+
+//  A stand-in for a control register at a fixed address.
+let mut ctrl = [0x80u8];
+let mut reg = VolatileBitSlice::<Msb0, u8>::from_slice_mut(&mut ctrl);
+reg.set(7, true);
+assert_eq!(reg.get(0), Some(true));
+reg.reborrow().slice(1 .. 4).store(0b101u8);
+assert_eq!(ctrl, [0xD1]);
+```
+
+[`reborrow`]: #method.reborrow
+[`slice`]: #method.slice
+[`split_at`]: #method.split_at
+**/
+pub struct VolatileBitSlice<'a, O = Local, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bitptr: BitPtr<T>,
+	_order: PhantomData<O>,
+	_ref: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, O, T> VolatileBitSlice<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs a view of a run of registers.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The address of the lowest register.
+	/// - `elts`: The number of `T` registers in the run.
+	///
+	/// # Returns
+	///
+	/// A view of all `elts * T::BITS` bits of the registers.
+	///
+	/// # Panics
+	///
+	/// This panics if `data` is not aligned for `T`, or if the run is too long
+	/// for a `BitSlice` to address.
+	///
+	/// # Safety
+	///
+	/// `data` must be valid for volatile reads and writes of `elts` consecutive
+	/// `T` values for the lifetime `'a`, and nothing else may write to them
+	/// through a non-volatile path during that lifetime.
+	pub unsafe fn from_raw_parts(data: *mut T, elts: usize) -> Self {
+		let bits = elts * T::BITS as usize;
+		Self::from_bitptr(BitPtr::new(data, 0u8.idx(), bits))
+	}
+
+	/// Constructs a volatile view of an ordinary slice of elements.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: The elements to view. They are inaccessible except through
+	///   the view until it is dropped.
+	///
+	/// # Returns
+	///
+	/// A view of all the bits in `slice`.
+	pub fn from_slice_mut(slice: &'a mut [T]) -> Self {
+		Self::from_bitptr(BitPtr::from_bitslice(slice.bits_mut::<O>()))
+	}
+
+	/// Counts the bits in the view.
+	pub fn len(&self) -> usize {
+		self.bitptr.len()
+	}
+
+	/// Tests if the view has no bits.
+	pub fn is_empty(&self) -> bool {
+		self.bitptr.len() == 0
+	}
+
+	/// Lends out the view for a shorter lifetime, so that a consuming method
+	/// can be called without giving up the original.
+	pub fn reborrow(&mut self) -> VolatileBitSlice<'_, O, T> {
+		VolatileBitSlice::from_bitptr(self.bitptr)
+	}
+
+	/// Reads a single bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to read.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index`, read with one volatile load of its
+	/// register, or `None` if `index` is not less than `self.len()`.
+	pub fn get(&self, index: usize) -> Option<bool> {
+		if index >= self.len() {
+			return None;
+		}
+		let (elt, bit) = self.bitptr.head().offset(index as isize);
+		let reg = unsafe {
+			self.bitptr.pointer().r().offset(elt).read_volatile()
+		};
+		Some(reg & *O::mask(bit) != T::FALSE)
+	}
+
+	/// Writes a single bit.
+	///
+	/// The register holding the bit is read once and written once, with its
+	/// other bits unchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to write.
+	/// - `value`: The value to write into the bit.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than `self.len()`.
+	pub fn set(&mut self, index: usize, value: bool) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let (elt, bit) = self.bitptr.head().offset(index as isize);
+		let mask = *O::mask(bit);
+		unsafe {
+			let reg = self.bitptr.pointer().w().offset(elt);
+			let old = reg.read_volatile();
+			reg.write_volatile(if value { old | mask } else { old & !mask });
+		}
+	}
+
+	/// Divides the view into two at an index.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `mid`: The index at which to split. It must be in the domain `0 ..=
+	///   self.len()`.
+	///
+	/// # Returns
+	///
+	/// - `.0`: A view of the bits in `0 .. mid`.
+	/// - `.1`: A view of the bits in `mid .. self.len()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `mid` is greater than `self.len()`.
+	pub fn split_at(self, mid: usize) -> (Self, Self) {
+		let (head, tail) = self.as_bitslice().split_at(mid);
+		(
+			Self::from_bitptr(head.bitptr()),
+			Self::from_bitptr(tail.bitptr()),
+		)
+	}
+
+	/// Narrows the view to a range of its bits.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `range`: The range of bit indices to keep.
+	///
+	/// # Returns
+	///
+	/// A view of the bits of `self` in `range`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is decreasing, or extends beyond `self.len()`.
+	pub fn slice<R>(self, range: R) -> Self
+	where R: RangeBounds<usize> {
+		use core::ops::Bound::*;
+		let from = match range.start_bound() {
+			Included(&n) => n,
+			Excluded(&n) => n + 1,
+			Unbounded => 0,
+		};
+		let upto = match range.end_bound() {
+			Included(&n) => n + 1,
+			Excluded(&n) => n,
+			Unbounded => self.len(),
+		};
+		Self::from_bitptr(self.as_bitslice()[from .. upto].bitptr())
+	}
+
+	/// Copies the registers under a field into a local buffer and runs a
+	/// function on the field’s bits within it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `func`: A function which loads from the bits of the field.
+	///
+	/// # Returns
+	///
+	/// The return value of `func`.
+	///
+	/// # Panics
+	///
+	/// The caller must have checked that `self` is no wider than 128 bits.
+	pub(crate) fn read_field<F, R>(&self, func: F) -> R
+	where F: FnOnce(&BitSlice<O, T>) -> R {
+		let mut regs = [T::FALSE; FIELD_ELTS];
+		func(self.read_registers(&mut regs))
+	}
+
+	/// Copies the registers under a field into a local buffer, runs a function
+	/// on the field’s bits within it, and writes every register back.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which stores into the bits of the field.
+	///
+	/// # Panics
+	///
+	/// The caller must have checked that `self` is no wider than 128 bits.
+	pub(crate) fn modify_field<F>(&mut self, func: F)
+	where F: FnOnce(&mut BitSlice<O, T>) {
+		let mut regs = [T::FALSE; FIELD_ELTS];
+		func(self.read_registers(&mut regs));
+		let data = self.bitptr.pointer().w();
+		for (n, reg) in regs[.. self.bitptr.elements()].iter().enumerate() {
+			unsafe {
+				data.add(n).write_volatile(*reg);
+			}
+		}
+	}
+
+	/// Reads each register under the view, in ascending address order, into
+	/// the front of a buffer.
+	///
+	/// # Returns
+	///
+	/// The bits of the buffer which correspond to the bits of the view.
+	fn read_registers<'b>(
+		&self,
+		regs: &'b mut [T; FIELD_ELTS],
+	) -> &'b mut BitSlice<O, T>
+	{
+		let elts = self.bitptr.elements();
+		let data = self.bitptr.pointer().r();
+		for (n, reg) in regs[.. elts].iter_mut().enumerate() {
+			*reg = unsafe { data.add(n).read_volatile() };
+		}
+		let head = *self.bitptr.head() as usize;
+		&mut regs[.. elts].bits_mut::<O>()[head .. head + self.len()]
+	}
+
+	/// Views the region as a `BitSlice`, for pointer arithmetic only.
+	///
+	/// The `BitSlice` handle does not refer to any memory itself, so producing
+	/// it does not touch the registers. It must not be read or written.
+	fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.bitptr.into_bitslice()
+	}
+
+	fn from_bitptr(bitptr: BitPtr<T>) -> Self {
+		Self {
+			bitptr,
+			_order: PhantomData,
+			_ref: PhantomData,
+		}
+	}
+}
+
+impl<O, T> Debug for VolatileBitSlice<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Prints the address and extent of the view, without reading it.
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("VolatileBitSlice")
+			.field("bitptr", &self.bitptr)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		fields::BitField,
+		order::{
+			Lsb0,
+			Msb0,
+		},
+	};
+
+	#[test]
+	fn bits() {
+		let mut regs = [0u16; 3];
+		let mut view = VolatileBitSlice::<Lsb0, u16>::from_slice_mut(&mut regs);
+		assert_eq!(view.len(), 48);
+		view.set(0, true);
+		view.set(17, true);
+		view.set(47, true);
+		assert_eq!(view.get(17), Some(true));
+		assert_eq!(view.get(18), Some(false));
+		assert_eq!(view.get(48), None);
+
+		let (head, tail) = view.reborrow().split_at(17);
+		assert_eq!(head.len(), 17);
+		assert_eq!(tail.get(0), Some(true));
+		let mut mid = tail.slice(.. 4);
+		assert_eq!(mid.len(), 4);
+		mid.set(3, true);
+		assert_eq!(view.get(20), Some(true));
+		view.set(17, false);
+		assert_eq!(regs, [0x0001, 0x0010, 0x8000]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Index out of range: 8 >= 8")]
+	fn bits_out_of_range() {
+		let mut reg = 0u8;
+		VolatileBitSlice::<Msb0, u8>::from_slice_mut(core::slice::from_mut(
+			&mut reg,
+		))
+		.set(8, true);
+	}
+
+	#[test]
+	fn fields() {
+		let mut regs = [0xFFu8; 4];
+		let mut view = VolatileBitSlice::<Msb0, u8>::from_slice_mut(&mut regs);
+		view.reborrow().slice(4 .. 20).store_be(0x1234u16);
+		assert_eq!(view.reborrow().slice(4 .. 20).load_be::<u16>(), 0x1234);
+		view.reborrow().slice(28 ..).store_le(0u8);
+		assert_eq!(view.reborrow().slice(20 ..).load_le::<u16>(), 0x0F0F);
+		assert_eq!(regs, [0xF1, 0x23, 0x4F, 0xF0]);
+
+		//  The widest field, starting at the last bit of a register.
+		let mut regs = [0u8; 17];
+		let mut view = VolatileBitSlice::<Lsb0, u8>::from_slice_mut(&mut regs);
+		let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+		view.reborrow().slice(7 .. 135).store_le(value);
+		assert_eq!(view.slice(7 .. 135).load_le::<u128>(), value);
+		assert_eq!(regs[0], 0);
+		assert_eq!(regs[16], 0);
+	}
+
+	#[test]
+	#[should_panic(expected = "Cannot load 8 bits from a 9-bit region")]
+	fn fields_too_wide() {
+		let mut regs = [0u8; 2];
+		VolatileBitSlice::<Lsb0, u8>::from_slice_mut(&mut regs)
+			.slice(.. 9)
+			.load_le::<u8>();
+	}
+}
@@ -0,0 +1,142 @@
+/*! Test that the fallible `BitVec` growth methods survive allocation failure.
+
+The global allocator in this test imitates a small, fixed heap: while it is
+armed, it refuses any request larger than a fixed budget. The `try_` methods
+must report each refusal as an error, and leave the vector intact and usable,
+where their counterparts would abort the process.
+!*/
+
+#[cfg(feature = "std")]
+use bitvec::{
+	error::BitError,
+	prelude::*,
+};
+
+#[cfg(feature = "std")]
+use std::{
+	alloc::{
+		GlobalAlloc,
+		Layout,
+		System,
+	},
+	ptr,
+	sync::atomic::{
+		AtomicBool,
+		Ordering,
+	},
+};
+
+/// The largest allocation, in bytes, that the armed allocator grants.
+#[cfg(feature = "std")]
+const BUDGET: usize = 1 << 16;
+
+#[cfg(feature = "std")]
+static ARMED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "std")]
+struct SmallHeap;
+
+#[cfg(feature = "std")]
+unsafe impl GlobalAlloc for SmallHeap {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		if ARMED.load(Ordering::Relaxed) && layout.size() > BUDGET {
+			return ptr::null_mut();
+		}
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+
+	unsafe fn realloc(
+		&self,
+		ptr: *mut u8,
+		layout: Layout,
+		new_size: usize,
+	) -> *mut u8
+	{
+		if ARMED.load(Ordering::Relaxed) && new_size > BUDGET {
+			return ptr::null_mut();
+		}
+		System.realloc(ptr, layout, new_size)
+	}
+}
+
+#[cfg(feature = "std")]
+#[global_allocator]
+static HEAP: SmallHeap = SmallHeap;
+
+#[cfg(feature = "std")]
+#[test]
+fn alloc_failure() {
+	let bits = BUDGET * 8;
+	ARMED.store(true, Ordering::Relaxed);
+
+	assert_eq!(
+		BitVec::<Lsb0, u8>::try_with_capacity(bits + 1).err(),
+		Some(BitError::AllocFailed {
+			size: BUDGET + 1,
+			align: 1,
+		}),
+	);
+
+	let mut bv = BitVec::<Lsb0, u8>::try_with_capacity(bits).unwrap();
+	bv.resize(bits, true);
+	//  `try_reserve` asks for double the capacity; `try_reserve_exact` asks for
+	//  only the next element.
+	assert_eq!(
+		bv.try_push(false),
+		Err(BitError::AllocFailed {
+			size: BUDGET * 2,
+			align: 1,
+		}),
+	);
+	assert_eq!(
+		bv.try_reserve_exact(1),
+		Err(BitError::AllocFailed {
+			size: BUDGET + 1,
+			align: 1,
+		}),
+	);
+	assert!(bv.try_insert(0, false).is_err());
+	assert!(bv.try_extend_from_slice(0u8.bits::<Lsb0>()).is_err());
+	assert_eq!(bv.len(), bits);
+	assert!(bv.all());
+
+	//  Growth within the existing capacity does not allocate.
+	bv.truncate(bits - 8);
+	assert!(bv.try_extend_from_slice(0u8.bits::<Lsb0>()).is_ok());
+	assert_eq!(bv.count_zeros(), 8);
+
+	ARMED.store(false, Ordering::Relaxed);
+	assert!(bv.try_push(true).is_ok());
+	assert!(bv.capacity() > bits);
+	assert_eq!(bv.count_zeros(), 8);
+	assert!(bv[bits]);
+
+	match bv.try_reserve(!0) {
+		Err(BitError::TooLong { len, .. }) => assert_eq!(len, !0),
+		other => panic!("Expected a length error, found {:?}", other),
+	}
+}
+
+/// Buffers grown through the global allocator keep their contents, including
+/// when the vector does not begin at the front of its first element.
+#[cfg(feature = "std")]
+#[test]
+fn try_grow_keeps_bits() {
+	let mut bv = BitVec::<Msb0, u16>::new();
+	for n in 0 .. 1000 {
+		bv.try_push(n % 3 == 0).unwrap();
+	}
+	assert!(bv.iter().enumerate().all(|(n, &bit)| bit == (n % 3 == 0)));
+
+	let mut offset = BitVec::from_bitslice(&bv[5 ..]);
+	offset.shrink_to_fit();
+	offset.try_reserve_exact(100).unwrap();
+	assert!(offset.capacity() >= 1095);
+	offset.try_extend_from_slice(&bv[.. 100]).unwrap();
+	assert_eq!(offset[.. 995], bv[5 ..]);
+	assert_eq!(offset[995 ..], bv[.. 100]);
+}